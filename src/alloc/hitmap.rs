@@ -0,0 +1,77 @@
+use std::cell::RefCell;
+
+use crate::layout::{Area, Pos};
+
+
+/// Maps positions to the widgets drawn there, for routing mouse events.
+///
+/// Areas are registered together with an identifier, typically while drawing.
+/// Registering takes `&self`, so that a `HitMap` can be shared with widgets
+/// during drawing. When areas overlap, the one registered last wins, as it is
+/// the one drawn on top.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::alloc::hitmap::HitMap;
+/// use cwinui::layout::{Area, Pos};
+///
+/// let hits = HitMap::new();
+/// hits.register(Area { x: 0, y: 0, width: 10, height: 5 }, "window");
+/// hits.register(Area { x: 2, y: 1, width: 4, height: 1 }, "button");
+///
+/// assert_eq!(hits.hit(Pos { x: 3, y: 1 }), Some("button"));
+/// assert_eq!(hits.hit(Pos { x: 3, y: 2 }), Some("window"));
+/// assert_eq!(hits.hit(Pos { x: 10, y: 0 }), None);
+///
+/// hits.clear();
+/// assert_eq!(hits.hit(Pos { x: 3, y: 1 }), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HitMap<Id> {
+    entries: RefCell<Vec<(Area, Id)>>,
+}
+
+impl<Id> HitMap<Id> {
+    /// Creates an empty `HitMap`.
+    pub const fn new() -> Self
+    {
+        Self {
+            entries: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Registers `area` as being occupied by `id`, on top of the previously
+    /// registered areas.
+    #[inline]
+    pub fn register(&self, area: Area, id: Id)
+    {
+        self.entries.borrow_mut().push((area, id));
+    }
+
+    /// Removes all the registered areas, e.g. before drawing a new frame.
+    #[inline]
+    pub fn clear(&self)
+    {
+        self.entries.borrow_mut().clear();
+    }
+
+    /// Finds the topmost (last registered) area containing `pos`.
+    pub fn hit(&self, pos: Pos) -> Option<Id>
+    where
+        Id: Clone,
+    {
+        self.entries.borrow()
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains_pos(pos))
+            .map(|(_, id)| id.clone())
+    }
+}
+
+impl<Id> Default for HitMap<Id> {
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
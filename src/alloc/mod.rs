@@ -1 +1,2 @@
 pub mod string;
+pub mod hitmap;
@@ -1,4 +1,6 @@
+use crate::backend::sgr::Sgr;
 use crate::style::{AsStyledStr, Style, StyledStr};
+use crate::util::{char_width, str_width};
 
 
 /// Owned version of [`StyledStr`].
@@ -8,8 +10,107 @@ pub struct StyledString {
     pub style: Style,
 }
 
+impl StyledString {
+    /// Creates a new, empty and unstyled `StyledString`.
+    #[inline]
+    pub const fn new() -> Self
+    {
+        Self {
+            content: String::new(),
+            style: Style::default(),
+        }
+    }
+
+    /// Creates a new, empty and unstyled `StyledString` with the capacity of
+    /// `capacity` bytes.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self
+    {
+        Self {
+            content: String::with_capacity(capacity),
+            style: Style::default(),
+        }
+    }
+
+    /// Appends `s` to the content.
+    #[inline]
+    pub fn push_str(&mut self, s: &str)
+    {
+        self.content.push_str(s);
+    }
+
+    /// Appends `c` to the content.
+    #[inline]
+    pub fn push(&mut self, c: char)
+    {
+        self.content.push(c);
+    }
+
+    /// Wraps the content in the SGR escape sequences for the style, see
+    /// [`StyledStr::to_ansi`].
+    #[inline]
+    pub fn to_ansi(&self) -> String
+    {
+        StyledStr::from(self).to_ansi()
+    }
+}
+
+/// Writes the content, ignoring the style.
+impl std::fmt::Display for StyledString {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str(&self.content)
+    }
+}
+
+impl Default for StyledString {
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/// Appends the content of `rhs`, keeping the style of `self`.
+///
+/// ```
+/// use cwinui::alloc::string::StyledString;
+/// use cwinui::style::{Color, Style, WithStyle};
+///
+/// let red = Style::default().fg(Color::Red);
+///
+/// let mut s = StyledString::from("ab".with_style(|_| red));
+/// s.push('c');
+/// s += "de";
+/// let s = s + "fg".with_style(|s| s.fg(Color::Blue));
+///
+/// assert_eq!(s.content, "abcdefg");
+/// assert_eq!(s.style, red);
+/// ```
+impl<S: AsStyledStr> std::ops::Add<S> for StyledString {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: S) -> Self
+    {
+        self += rhs;
+
+        self
+    }
+}
+
+/// Appends the content of `rhs`, keeping the style of `self`.
+impl<S: AsStyledStr> std::ops::AddAssign<S> for StyledString {
+    #[inline]
+    fn add_assign(&mut self, rhs: S)
+    {
+        self.push_str(rhs.as_styled_str().content);
+    }
+}
+
 impl AsStyledStr for &StyledString {
-    fn as_styled_str(&self) -> StyledStr
+    fn as_styled_str(&self) -> StyledStr<'_>
     {
         StyledStr {
             content: self.content.as_str(),
@@ -42,6 +143,79 @@ impl<'s> StyledStr<'s> {
     {
         StyledString::from(*self)
     }
+
+    /// Wraps the content in the SGR escape sequences for the style.
+    ///
+    /// The content is preceded by the sequence setting the style and followed
+    /// by a reset. This is meant for writing to outputs other than a
+    /// [`Backend`](crate::backend::Backend).
+    ///
+    /// ```
+    /// use cwinui::style::{Color, TextStyle, WithStyle};
+    ///
+    /// let s = "error"
+    ///     .with_style(|s| s.fg(Color::Red).text_style(TextStyle::BOLD));
+    ///
+    /// assert_eq!(s.to_string(), "error");
+    /// assert_eq!(s.to_ansi(), "\x1b[0;1;31merror\x1b[0m");
+    /// ```
+    #[inline]
+    pub fn to_ansi(&self) -> String
+    {
+        format!("{}{}\x1b[0m", Sgr::from(self.style), self.content)
+    }
+
+    /// Truncates to `max_cols` display columns, marking the truncation with
+    /// `'…'`.
+    ///
+    /// See [`StyledStr::truncate_with`].
+    #[inline]
+    pub fn truncate_ellipsis(&self, max_cols: usize) -> StyledString
+    {
+        self.truncate_with(max_cols, '…')
+    }
+
+    /// Truncates to `max_cols` display columns, marking the truncation with
+    /// `ellipsis`.
+    ///
+    /// When the text is wider than `max_cols`, as many columns as fit next to
+    /// `ellipsis` are kept and `ellipsis` is appended. If not even `ellipsis`
+    /// fits, the text is just cut off at `max_cols`.
+    ///
+    /// ```
+    /// use cwinui::style::StyledStr;
+    ///
+    /// let s = StyledStr::from("Settings");
+    ///
+    /// assert_eq!(s.truncate_ellipsis(8).content, "Settings");
+    /// assert_eq!(s.truncate_ellipsis(6).content, "Setti…");
+    /// assert_eq!(s.truncate_with(6, '>').content, "Setti>");
+    ///
+    /// // Display width is taken into account.
+    /// let s = StyledStr::from("日本語");
+    ///
+    /// assert_eq!(s.truncate_ellipsis(5).content, "日本…");
+    /// assert_eq!(s.truncate_ellipsis(4).content, "日…");
+    /// assert_eq!(s.truncate_ellipsis(1).content, "…");
+    /// assert_eq!(s.truncate_with(0, '…').content, "");
+    /// ```
+    pub fn truncate_with(&self, max_cols: usize, ellipsis: char) -> StyledString
+    {
+        if str_width(self.content) <= max_cols {
+            return self.to_owned();
+        }
+
+        let ellipsis_width = char_width(ellipsis);
+        if ellipsis_width > max_cols {
+            return self.slice_cols(0..max_cols).to_owned();
+        }
+
+        let mut truncated = self.slice_cols(0..max_cols - ellipsis_width)
+            .to_owned();
+        truncated.push(ellipsis);
+
+        truncated
+    }
 }
 
 impl<'s> From<&'s StyledString> for StyledStr<'s>
@@ -54,3 +228,64 @@ impl<'s> From<&'s StyledString> for StyledStr<'s>
         }
     }
 }
+
+
+/// Creates a [`StyledString`] with formatted content, like `format!`.
+///
+/// The formatting arguments can be followed by a `;` and a list of style
+/// attributes: `fg` and `bg` for the colors and `ts` for the [`TextStyle`].
+/// Attributes that are not specified are left unset.
+///
+/// [`TextStyle`]: crate::style::TextStyle
+///
+/// # Examples
+///
+/// ```
+/// use cwinui::styled;
+/// use cwinui::style::{Color, Style, TextStyle};
+///
+/// let code = 42;
+/// let s = styled!("Error: {}", code; fg = Color::Red, ts = TextStyle::BOLD);
+///
+/// assert_eq!(s.content, "Error: 42");
+/// assert_eq!(
+///     s.style,
+///     Style::default().fg(Color::Red).text_style(TextStyle::BOLD)
+/// );
+///
+/// let s = styled!("{code:>4}"; bg = Color::Blue);
+/// assert_eq!(s.content, "  42");
+/// assert_eq!(s.style.fg_color, None);
+///
+/// assert_eq!(styled!("plain").style, Style::default());
+/// ```
+#[macro_export]
+macro_rules! styled {
+    (@set $style:ident, fg, $val:expr) => {
+        $style = $style.fg($val)
+    };
+    (@set $style:ident, bg, $val:expr) => {
+        $style = $style.bg($val)
+    };
+    (@set $style:ident, ts, $val:expr) => {
+        $style = $style.text_style($val)
+    };
+    (
+        $fmt:literal $(, $arg:expr)* $(,)?;
+        $($key:ident = $val:expr),+ $(,)?
+    ) => {{
+        let mut style = $crate::style::Style::default();
+        $( $crate::styled!(@set style, $key, $val); )+
+
+        $crate::alloc::string::StyledString {
+            content: ::std::format!($fmt $(, $arg)*),
+            style,
+        }
+    }};
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {
+        $crate::alloc::string::StyledString {
+            content: ::std::format!($fmt $(, $arg)*),
+            style: $crate::style::Style::default(),
+        }
+    };
+}
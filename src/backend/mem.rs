@@ -0,0 +1,327 @@
+use crate::buffer::{Buffer, Cursor};
+use crate::layout::Dim;
+use crate::style::{ColorSupport, Style, StyledChar};
+use crate::render::Render;
+use crate::util::char_width;
+
+use super::Backend;
+use super::termion::alloc::write_line;
+
+
+/// In-memory backend that never touches a terminal.
+///
+/// Flushing copies the rendered frame to the presented cells, which can be
+/// inspected with [`MemBackend::cells`]. This is useful for testing and
+/// headless use.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::Render;
+/// use cwinui::layout::{Dim, Pos};
+///
+/// let mut backend = MemBackend::new(4, 2);
+///
+/// backend.render(|buf| buf.set_str(Pos { x: 1, y: 1 }, "ab"));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.dimensions(), Dim { width: 4, height: 2 });
+/// let chars: String = backend.cells().iter().map(|&(c, _)| c).collect();
+/// assert_eq!(chars, "     ab ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MemBackend {
+    width: u16,
+    height: u16,
+    chars: Vec<char>,
+    styles: Vec<Style>,
+    cursor: Cursor,
+    presented: Vec<(char, Style)>,
+    keep_prev: bool,
+    blank: StyledChar,
+    tab_width: u16,
+}
+
+impl MemBackend {
+    /// Creates a new `MemBackend` of `width` by `height` cells.
+    pub fn new(width: u16, height: u16) -> Self
+    {
+        let size = width as usize * height as usize;
+
+        Self {
+            width,
+            height,
+            chars: vec![' '; size],
+            styles: vec![Style::default().clean(); size],
+            cursor: Cursor::new(),
+            presented: vec![(' ', Style::default().clean()); size],
+            keep_prev: false,
+            blank: StyledChar { content: ' ', style: Style::default() },
+            tab_width: 8,
+        }
+    }
+
+    /// Resizes the backend to `width` by `height` cells.
+    ///
+    /// Both the rendered frame and the presented cells are cleared.
+    pub fn resize(&mut self, width: u16, height: u16)
+    {
+        *self = Self {
+            keep_prev: self.keep_prev,
+            blank: self.blank,
+            tab_width: self.tab_width,
+            ..Self::new(width, height)
+        };
+    }
+
+    /// Sets whether the presented cells are exposed to the renderer as the
+    /// previous frame, see [`Render::prev_char`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::Pos;
+    ///
+    /// let mut backend = MemBackend::new(3, 1);
+    /// let pos = Pos { x: 1, y: 0 };
+    ///
+    /// backend.render(|buf| buf.set_char(pos, 'a'));
+    /// backend.flush().unwrap();
+    ///
+    /// backend.render(|buf| assert_eq!(buf.prev_char(pos), None));
+    ///
+    /// backend.keep_prev_frame(true);
+    /// backend.render(|buf| {
+    ///     assert_eq!(buf.prev_char(pos).map(|c| c.content), Some('a'));
+    /// });
+    /// ```
+    #[inline]
+    pub fn keep_prev_frame(&mut self, keep: bool)
+    {
+        self.keep_prev = keep;
+    }
+
+    /// Sets the character and style that each frame is cleared with before
+    /// rendering, see [`Buffer::set_clear`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::Pos;
+    /// use cwinui::style::Style;
+    ///
+    /// let mut backend = MemBackend::new(4, 1);
+    /// backend.set_clear('·', Style::default());
+    ///
+    /// backend.render(|buf| buf.set_str(Pos { x: 1, y: 0 }, "ab"));
+    /// backend.flush().unwrap();
+    ///
+    /// assert_eq!(backend.to_string(), "·ab·");
+    /// ```
+    #[inline]
+    pub fn set_clear(&mut self, c: char, style: Style)
+    {
+        self.blank = StyledChar { content: c, style };
+    }
+
+    /// Sets the width of tab stops that each frame is rendered with, see
+    /// [`Buffer::set_tab_width`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::Pos;
+    ///
+    /// let mut backend = MemBackend::new(4, 1);
+    /// backend.set_tab_width(2);
+    ///
+    /// for _ in 0..2 {
+    ///     backend.render(|buf| buf.set_str(Pos::ZERO, "a\tb"));
+    ///     backend.flush().unwrap();
+    ///
+    ///     assert_eq!(backend.to_string(), "a b ");
+    /// }
+    ///
+    /// // The tab width is kept when resizing.
+    /// backend.resize(4, 1);
+    /// backend.render(|buf| buf.set_str(Pos::ZERO, "a\tb"));
+    /// backend.flush().unwrap();
+    ///
+    /// assert_eq!(backend.to_string(), "a b ");
+    /// ```
+    #[inline]
+    pub fn set_tab_width(&mut self, width: u16)
+    {
+        self.tab_width = width;
+    }
+
+    /// Gets the dimensions.
+    #[inline]
+    pub fn dimensions(&self) -> Dim
+    {
+        Dim {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Gets the cells presented by the last flush, row by row.
+    #[inline]
+    pub fn cells(&self) -> &[(char, Style)]
+    {
+        &self.presented
+    }
+
+    /// Renders the presented cells to a string, including the escape
+    /// sequences that would be written to a terminal.
+    ///
+    /// The rows are separated by `'\n'`. The styling of each row is the same
+    /// as when flushing the termion backends. For the text without styles,
+    /// see the [`Display`](std::fmt::Display) implementation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::Pos;
+    /// use cwinui::style::{Style, TextStyle, WithStyle};
+    ///
+    /// let mut backend = MemBackend::new(3, 1);
+    ///
+    /// backend.render(|buf| {
+    ///     let bold = Style::default().text_style(TextStyle::BOLD);
+    ///     buf.set_str(Pos { x: 1, y: 0 }, "b".with_style(|_| bold));
+    /// });
+    /// backend.flush().unwrap();
+    ///
+    /// let ansi = backend.to_ansi_string();
+    /// assert_eq!(ansi, "\x1b[0m \x1b[0;1mb\x1b[0m ");
+    /// assert_ne!(ansi, backend.to_string());
+    /// ```
+    pub fn to_ansi_string(&self) -> String
+    {
+        let (mut chars, mut styles): (Vec<_>, Vec<_>) = self.presented
+            .iter()
+            .copied()
+            .unzip();
+        let mut cursor = Cursor::new();
+        let buffer = Buffer::new(
+            self.width,
+            self.height,
+            &mut chars,
+            &mut styles,
+            &mut cursor
+        );
+
+        let mut out = Vec::new();
+        for y in 0..self.height {
+            if y > 0 {
+                out.push(b'\n');
+            }
+            // Writing into a `Vec` cannot fail.
+            let _ = write_line(&mut out, &buffer, y, ColorSupport::TrueColor);
+        }
+
+        String::from_utf8(out)
+            .expect("the output should be valid UTF-8")
+    }
+}
+
+/// Writes the presented cells without styles, with the rows separated by
+/// `'\n'`.
+///
+/// The cells covered by wide characters are skipped, so that the text reads
+/// naturally.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::Render;
+/// use cwinui::layout::Pos;
+///
+/// let mut backend = MemBackend::new(5, 2);
+///
+/// backend.render(|buf| {
+///     buf.set_str(Pos { x: 0, y: 0 }, "ab");
+///     buf.set_str(Pos { x: 1, y: 1 }, "日本");
+/// });
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "ab   \n 日本");
+/// ```
+impl std::fmt::Display for MemBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+    {
+        use std::fmt::Write;
+
+        let width = self.width as usize;
+        if width == 0 {
+            return Ok(());
+        }
+
+        for (y, row) in self.presented.chunks(width).enumerate() {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+
+            let mut covered = false;
+            for &(c, _) in row {
+                if covered {
+                    covered = false;
+                    continue;
+                }
+                covered = char_width(c) > 1;
+
+                f.write_char(c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Backend for MemBackend {
+    type Renderer<'r> = Buffer<'r>;
+    type FlushError = std::convert::Infallible;
+
+    fn render<'a, 'r, F>(&'a mut self, ui: F)
+    where
+        F: FnOnce(&mut Self::Renderer<'r>),
+        'a: 'r,
+    {
+        let mut buffer = Buffer::new(
+            self.width,
+            self.height,
+            &mut self.chars,
+            &mut self.styles,
+            &mut self.cursor
+        );
+        if self.keep_prev {
+            buffer = buffer.with_prev(&self.presented);
+        }
+        buffer.set_clear(self.blank.content, self.blank.style);
+        buffer.set_tab_width(self.tab_width);
+        buffer.clear();
+
+        ui(&mut buffer);
+    }
+
+    fn flush(&mut self) -> Result<(), Self::FlushError>
+    {
+        let cells = self.chars.iter().copied().zip(self.styles.iter().copied());
+        for (presented, cell) in self.presented.iter_mut().zip(cells) {
+            *presented = cell;
+        }
+
+        Ok(())
+    }
+}
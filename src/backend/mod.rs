@@ -2,9 +2,12 @@ use crate::render::{Render, Draw};
 
 
 mod termion;
+mod mem;
+pub mod sgr;
 
 
-pub use termion::alloc::{TermionFixed, TermionDyn};
+pub use termion::alloc::{TermionFixed, TermionDyn, WriterBackend};
+pub use mem::MemBackend;
 
 
 pub trait Backend {
@@ -0,0 +1,155 @@
+//! SGR (Select Graphic Rendition) escape sequences.
+//!
+//! This is the single place where [`Color`]s and [`TextStyle`]s are mapped to
+//! SGR parameters.
+//!
+//! Attributes are never turned off individually. Some of them share the code
+//! that turns them off (e.g. `22` for both bold and dim), so every sequence
+//! starts with a full reset instead.
+
+
+use std::fmt::{Display, Formatter, Write};
+
+use crate::style::{Color, ColorSupport, Style, TextStyle};
+
+
+/// SGR parameters of the individual text styles.
+const TEXT_STYLE_PARAMS: [(TextStyle, u8); 7] = [
+    (TextStyle::BOLD,          1),
+    (TextStyle::DIM,           2),
+    (TextStyle::ITALIC,        3),
+    (TextStyle::UNDERLINE,     4),
+    (TextStyle::BLINK,         5),
+    (TextStyle::INVERT,        7),
+    (TextStyle::STRIKETHROUGH, 9),
+];
+
+
+/// Single SGR sequence setting all the attributes of a cell at once.
+///
+/// The sequence starts with a reset, so that no attributes of the previous
+/// cells are carried over. Default colors are left out, as they are implied by
+/// the reset.
+///
+/// # Examples
+///
+/// ```
+/// use cwinui::backend::sgr::Sgr;
+/// use cwinui::style::{Color, Style, TextStyle};
+///
+/// let sgr = Sgr {
+///     text_style: TextStyle::BOLD,
+///     fg: Color::Red,
+///     bg: Color::Blue,
+/// };
+/// assert_eq!(sgr.to_string(), "\x1b[0;1;31;44m");
+///
+/// let sgr = Sgr {
+///     text_style: TextStyle::UNDERLINE | TextStyle::INVERT,
+///     fg: Color::Ansi(208),
+///     bg: Color::Rgb(1, 2, 3),
+/// };
+/// assert_eq!(sgr.to_string(), "\x1b[0;4;7;38;5;208;48;2;1;2;3m");
+///
+/// let sgr = Sgr::from(Style::default().text_style(TextStyle::STRIKETHROUGH));
+/// assert_eq!(sgr.to_string(), "\x1b[0;9m");
+///
+/// let sgr = Sgr::from(
+///     Style::default().text_style(TextStyle::BOLD | TextStyle::DIM)
+/// );
+/// assert_eq!(sgr.to_string(), "\x1b[0;1;2m");
+///
+/// let sgr = Sgr::from(Style::default().fg(Color::LightGreen));
+/// assert_eq!(sgr.to_string(), "\x1b[0;92m");
+///
+/// let sgr = Sgr::from(Style::default().fg(Color::LightWhite));
+/// assert_eq!(sgr.to_string(), "\x1b[0;97m");
+/// let sgr = Sgr::from(Style::default().bg(Color::LightWhite));
+/// assert_eq!(sgr.to_string(), "\x1b[0;107m");
+/// let sgr = Sgr::from(Style::default().bg(Color::LightCyan));
+/// assert_eq!(sgr.to_string(), "\x1b[0;106m");
+///
+/// assert_eq!(Sgr::from(Style::default()).to_string(), "\x1b[0m");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Sgr {
+    pub text_style: TextStyle,
+    pub fg: Color,
+    pub bg: Color,
+}
+
+impl Sgr {
+    /// Downgrades both colors to `level` of color support, see
+    /// [`Color::downgrade`].
+    #[inline]
+    pub fn downgrade(self, level: ColorSupport) -> Self
+    {
+        Self {
+            fg: self.fg.downgrade(level),
+            bg: self.bg.downgrade(level),
+            ..self
+        }
+    }
+}
+
+impl From<Style> for Sgr {
+    /// Unset attributes are resolved to their defaults.
+    #[inline]
+    fn from(style: Style) -> Self
+    {
+        Self {
+            text_style: style.text_style.unwrap_or_default(),
+            fg: style.fg_color.unwrap_or_default(),
+            bg: style.bg_color.unwrap_or_default(),
+        }
+    }
+}
+
+impl Display for Sgr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str("\x1b[0")?;
+
+        for (ts, param) in TEXT_STYLE_PARAMS {
+            if self.text_style.contains(ts) {
+                write!(f, ";{param}")?;
+            }
+        }
+
+        write_color(f, self.fg, 30)?;
+        write_color(f, self.bg, 40)?;
+
+        f.write_char('m')
+    }
+}
+
+
+/// Writes the parameters of `color`, each preceded by a `;`.
+///
+/// `base` is `30` for foreground and `40` for background colors.
+fn write_color<W: Write>(w: &mut W, color: Color, base: u8) -> std::fmt::Result
+{
+    let basic = match color {
+        Color::Normal       => return Ok(()),
+        Color::Black        => 0,
+        Color::Red          => 1,
+        Color::Green        => 2,
+        Color::Yellow       => 3,
+        Color::Blue         => 4,
+        Color::Magenta      => 5,
+        Color::Cyan         => 6,
+        Color::White        => 7,
+        Color::LightBlack   => 60,
+        Color::LightRed     => 61,
+        Color::LightGreen   => 62,
+        Color::LightYellow  => 63,
+        Color::LightBlue    => 64,
+        Color::LightMagenta => 65,
+        Color::LightCyan    => 66,
+        Color::LightWhite   => 67,
+        Color::Ansi(c)      => return write!(w, ";{};5;{c}", base + 8),
+        Color::Rgb(r, g, b) => return write!(w, ";{};2;{r};{g};{b}", base + 8),
+    };
+
+    write!(w, ";{}", base + basic)
+}
@@ -7,11 +7,13 @@ pub mod alloc {
     use termion::input::MouseTerminal;
 
     use crate::buffer::{Buffer, Cursor};
-    use crate::style::{Style, Color, TextStyle};
-    use crate::util::offset;
+    use crate::layout::{Dim, Pos};
+    use crate::style::{ColorSupport, Style, StyledChar};
+    use crate::util::{offset, char_width};
     use crate::render::Render;
 
     use super::{Backend, console};
+    use crate::backend::sgr::Sgr;
 
 
     /// Termion-based fixed-size backend.
@@ -23,6 +25,12 @@ pub mod alloc {
         styles: Box<[Style]>,
         cursor: Cursor,
         stdout: RawTerminal<MouseTerminal<Stdout>>,
+        alt_screen: bool,
+        front: Front,
+        keep_prev: bool,
+        colors: ColorSupport,
+        blank: StyledChar,
+        tab_width: u16,
     }
 
     impl<const W: u16, const H: u16> std::fmt::Debug for TermionFixed<W, H> {
@@ -37,6 +45,11 @@ pub mod alloc {
         ///
         /// Should be called only once, as it modifies the state of the
         /// terminal.
+        ///
+        /// The frames are drawn starting at the row of the cursor, while mouse
+        /// events are reported in screen coordinates. Widgets map mouse events
+        /// assuming that the frame starts at the top left of the screen, so
+        /// mouse support needs [`Self::init_alt`].
         pub fn init() -> std::io::Result<Self>
         {
             let mut stdout = MouseTerminal::from(std::io::stdout())
@@ -50,10 +63,80 @@ pub mod alloc {
                 chars: vec![' '; buf_size].into_boxed_slice(),
                 styles: vec![Style::default().clean(); buf_size]
                     .into_boxed_slice(),
-                cursor: Cursor { x: 0, y: 0, hidden: true },
+                cursor: Cursor::new(),
                 stdout,
+                alt_screen: false,
+                front: Front::new(),
+                keep_prev: false,
+                colors: ColorSupport::TrueColor,
+                blank: StyledChar { content: ' ', style: Style::default() },
+                tab_width: 8,
             })
         }
+
+        /// Initialises and creates the backend, using the alternate screen.
+        ///
+        /// The contents of the main screen are restored on drop. Should be
+        /// called only once, as it modifies the state of the terminal.
+        pub fn init_alt() -> std::io::Result<Self>
+        {
+            let mut backend = Self::init()?;
+
+            console::enter_alt_screen(&mut backend.stdout)?;
+            backend.alt_screen = true;
+
+            Ok(backend)
+        }
+
+        /// Forces the next flush to rewrite the whole screen.
+        ///
+        /// By default, only the cells that changed since the last flush are
+        /// rewritten. This is useful when the contents of the screen have been
+        /// disturbed by something else.
+        #[inline]
+        pub fn force_redraw(&mut self)
+        {
+            self.front.invalidate();
+        }
+
+        /// Sets whether the previously flushed frame is exposed to the
+        /// renderer, see [`Render::prev_char`].
+        #[inline]
+        pub fn keep_prev_frame(&mut self, keep: bool)
+        {
+            self.keep_prev = keep;
+        }
+
+        /// Sets the level of color support of the terminal.
+        ///
+        /// Colors that are not supported are converted to the nearest
+        /// supported ones when flushing, see [`Color::downgrade`]. Defaults to
+        /// [`ColorSupport::TrueColor`], i.e. all colors are written as they
+        /// are.
+        ///
+        /// [`Color::downgrade`]: crate::style::Color::downgrade
+        #[inline]
+        pub fn set_color_support(&mut self, level: ColorSupport)
+        {
+            self.colors = level;
+            self.front.invalidate();
+        }
+
+        /// Sets the character and style that each frame is cleared with
+        /// before rendering, see [`Buffer::set_clear`].
+        #[inline]
+        pub fn set_clear(&mut self, c: char, style: Style)
+        {
+            self.blank = StyledChar { content: c, style };
+        }
+
+        /// Sets the width of tab stops that each frame is rendered with, see
+        /// [`Buffer::set_tab_width`].
+        #[inline]
+        pub fn set_tab_width(&mut self, width: u16)
+        {
+            self.tab_width = width;
+        }
     }
 
     impl<const W: u16, const H: u16> Backend for TermionFixed<W, H>
@@ -73,6 +156,13 @@ pub mod alloc {
                 &mut self.styles,
                 &mut self.cursor
             );
+            if self.keep_prev {
+                if let Some(prev) = self.front.frame(W, H) {
+                    buffer = buffer.with_prev(prev);
+                }
+            }
+            buffer.set_clear(self.blank.content, self.blank.style);
+            buffer.set_tab_width(self.tab_width);
             buffer.clear();
 
             ui(&mut buffer);
@@ -88,14 +178,16 @@ pub mod alloc {
                 &mut self.cursor
             );
 
-            flush_buf(&mut self.stdout, &buffer)
+            flush_buf(&mut self.stdout, &buffer, &mut self.front, self.colors)
         }
     }
 
     impl<const W: u16, const H: u16> Drop for TermionFixed<W, H> {
         fn drop(&mut self)
         {
-            let _ = restore_terminal(&mut self.stdout, H);
+            let _ = if self.alt_screen
+                { restore_alt_screen(&mut self.stdout) }
+                else { restore_terminal(&mut self.stdout, &mut self.front, H) };
         }
     }
 
@@ -108,6 +200,12 @@ pub mod alloc {
         styles: Vec<Style>,
         cursor: Cursor,
         stdout: RawTerminal<MouseTerminal<Stdout>>,
+        alt_screen: bool,
+        front: Front,
+        keep_prev: bool,
+        colors: ColorSupport,
+        blank: StyledChar,
+        tab_width: u16,
     }
 
     impl std::fmt::Debug for TermionDyn {
@@ -118,6 +216,12 @@ pub mod alloc {
     }
 
     impl TermionDyn {
+        /// Initialises and creates the backend.
+        ///
+        /// The frames are drawn starting at the row of the cursor, while mouse
+        /// events are reported in screen coordinates. Widgets map mouse events
+        /// assuming that the frame starts at the top left of the screen, so
+        /// mouse support needs [`Self::init_alt`].
         pub fn init() -> std::io::Result<Self>
         {
             let mut stdout = MouseTerminal::from(std::io::stdout())
@@ -130,15 +234,107 @@ pub mod alloc {
             let buf_size = width as usize * height as usize;
 
             Ok(Self {
-                last_width: 0,
-                last_height: 0,
+                last_width: width,
+                last_height: height,
                 last_flush_height: 0,
                 chars: vec![' '; buf_size],
                 styles: vec![Style::default().clean(); buf_size],
-                cursor: Cursor { x: 0, y: 0, hidden: true },
+                cursor: Cursor::new(),
                 stdout,
+                alt_screen: false,
+                front: Front::new(),
+                keep_prev: false,
+                colors: ColorSupport::TrueColor,
+                blank: StyledChar { content: ' ', style: Style::default() },
+                tab_width: 8,
             })
         }
+
+        /// Initialises and creates the backend, using the alternate screen.
+        ///
+        /// The contents of the main screen are restored on drop.
+        pub fn init_alt() -> std::io::Result<Self>
+        {
+            let mut backend = Self::init()?;
+
+            console::enter_alt_screen(&mut backend.stdout)?;
+            backend.alt_screen = true;
+
+            Ok(backend)
+        }
+
+        /// Forces the next flush to rewrite the whole screen.
+        ///
+        /// By default, only the cells that changed since the last flush are
+        /// rewritten. This is useful when the contents of the screen have been
+        /// disturbed by something else.
+        #[inline]
+        pub fn force_redraw(&mut self)
+        {
+            self.front.invalidate();
+        }
+
+        /// Sets whether the previously flushed frame is exposed to the
+        /// renderer, see [`Render::prev_char`].
+        #[inline]
+        pub fn keep_prev_frame(&mut self, keep: bool)
+        {
+            self.keep_prev = keep;
+        }
+
+        /// Sets the level of color support of the terminal.
+        ///
+        /// Colors that are not supported are converted to the nearest
+        /// supported ones when flushing, see [`Color::downgrade`]. Defaults to
+        /// [`ColorSupport::TrueColor`], i.e. all colors are written as they
+        /// are.
+        ///
+        /// [`Color::downgrade`]: crate::style::Color::downgrade
+        #[inline]
+        pub fn set_color_support(&mut self, level: ColorSupport)
+        {
+            self.colors = level;
+            self.front.invalidate();
+        }
+
+        /// Sets the character and style that each frame is cleared with
+        /// before rendering, see [`Buffer::set_clear`].
+        #[inline]
+        pub fn set_clear(&mut self, c: char, style: Style)
+        {
+            self.blank = StyledChar { content: c, style };
+        }
+
+        /// Sets the width of tab stops that each frame is rendered with, see
+        /// [`Buffer::set_tab_width`].
+        #[inline]
+        pub fn set_tab_width(&mut self, width: u16)
+        {
+            self.tab_width = width;
+        }
+
+        /// Gets the last known dimensions of the terminal.
+        ///
+        /// These are the dimensions of the last rendered frame, or the
+        /// dimensions at initialisation if nothing has been rendered yet.
+        #[inline]
+        pub fn dimensions(&self) -> Dim
+        {
+            Dim {
+                width: self.last_width,
+                height: self.last_height,
+            }
+        }
+
+        /// Checks if the terminal has been resized since the last render.
+        ///
+        /// Returns `false` if the size of the terminal cannot be queried.
+        pub fn resized_since_last_render(&self) -> bool
+        {
+            termion::terminal_size()
+                .map(|size| size != (self.last_width, self.last_height))
+                .unwrap_or(false)
+        }
     }
 
     impl Backend for TermionDyn {
@@ -171,6 +367,14 @@ pub mod alloc {
                 &mut self.styles,
                 &mut self.cursor
             );
+            if self.keep_prev {
+                let prev = self.front.frame(self.last_width, self.last_height);
+                if let Some(prev) = prev {
+                    buffer = buffer.with_prev(prev);
+                }
+            }
+            buffer.set_clear(self.blank.content, self.blank.style);
+            buffer.set_tab_width(self.tab_width);
             buffer.clear();
 
             ui(&mut buffer);
@@ -186,7 +390,7 @@ pub mod alloc {
                 &mut self.cursor
             );
 
-            flush_buf(&mut self.stdout, &buffer)?;
+            flush_buf(&mut self.stdout, &buffer, &mut self.front, self.colors)?;
 
             self.last_flush_height = self.last_height;
 
@@ -197,120 +401,547 @@ pub mod alloc {
     impl Drop for TermionDyn {
         fn drop(&mut self)
         {
-            let _ = restore_terminal(&mut self.stdout, self.last_flush_height);
+            let _ = if self.alt_screen
+                { restore_alt_screen(&mut self.stdout) }
+                else {
+                    restore_terminal(
+                        &mut self.stdout,
+                        &mut self.front,
+                        self.last_flush_height
+                    )
+                };
         }
     }
 
-    fn flush_buf<W: Write>(writer: &mut W, buffer: &Buffer)
-        -> Result<(), std::io::Error>
+    /// Backend writing the terminal output to an arbitrary writer.
+    ///
+    /// The same escape sequences as with the other termion backends are
+    /// written, which makes it possible to direct them e.g. into a `Vec<u8>`,
+    /// a file or a socket. Since the writer is not necessarily a terminal, the
+    /// dimensions are set explicitly and no terminal setup (raw mode, hiding
+    /// the cursor) is done by [`WriterBackend::new`]. For terminals, see
+    /// [`WriterBackend::init`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, WriterBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::Pos;
+    ///
+    /// let mut backend = WriterBackend::new(Vec::new(), 4, 1);
+    ///
+    /// backend.render(|buf| buf.set_str(Pos { x: 1, y: 0 }, "ab"));
+    /// backend.flush().unwrap();
+    ///
+    /// let output = String::from_utf8_lossy(backend.writer());
+    /// assert!(output.contains("ab"));
+    ///
+    /// // Nothing is written without any cells.
+    /// let mut backend = WriterBackend::new(Vec::new(), 4, 0);
+    /// backend.render(|_| {});
+    /// backend.flush().unwrap();
+    /// assert!(backend.writer().is_empty());
+    /// ```
+    pub struct WriterBackend<W: Write> {
+        width: u16,
+        height: u16,
+        chars: Vec<char>,
+        styles: Vec<Style>,
+        cursor: Cursor,
+        writer: W,
+        restore_on_drop: bool,
+        front: Front,
+        keep_prev: bool,
+        colors: ColorSupport,
+        blank: StyledChar,
+        tab_width: u16,
+    }
+
+    impl<W: Write> std::fmt::Debug for WriterBackend<W> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+        {
+            f.write_fmt(format_args!(
+                "WriterBackend({}x{})",
+                self.width,
+                self.height
+            ))
+        }
+    }
+
+    impl<W: Write> WriterBackend<W> {
+        /// Creates the backend, without modifying the state of the writer.
+        pub fn new(writer: W, width: u16, height: u16) -> Self
+        {
+            let buf_size = width as usize * height as usize;
+
+            Self {
+                width,
+                height,
+                chars: vec![' '; buf_size],
+                styles: vec![Style::default().clean(); buf_size],
+                cursor: Cursor::new(),
+                writer,
+                restore_on_drop: false,
+                front: Front::new(),
+                keep_prev: false,
+                colors: ColorSupport::TrueColor,
+                blank: StyledChar { content: ' ', style: Style::default() },
+                tab_width: 8,
+            }
+        }
+
+        /// Creates the backend for a terminal writer.
+        ///
+        /// Hides the cursor and restores the terminal on drop. Raw mode is up
+        /// to the caller, e.g. by passing a
+        /// [`RawTerminal`](termion::raw::RawTerminal).
+        pub fn init(writer: W, width: u16, height: u16)
+            -> std::io::Result<Self>
+        {
+            let mut backend = Self::new(writer, width, height);
+
+            console::hide_cursor(&mut backend.writer)?;
+            backend.restore_on_drop = true;
+
+            Ok(backend)
+        }
+
+        /// Resizes the paint area to `width` by `height` cells.
+        pub fn resize(&mut self, width: u16, height: u16)
+        {
+            let buf_size = width as usize * height as usize;
+
+            self.chars.resize(buf_size, ' ');
+            self.styles.resize(buf_size, Style::default().clean());
+            self.width = width;
+            self.height = height;
+        }
+
+        /// Forces the next flush to rewrite the whole screen.
+        ///
+        /// By default, only the cells that changed since the last flush are
+        /// rewritten. This is useful when the contents of the screen have been
+        /// disturbed by something else.
+        #[inline]
+        pub fn force_redraw(&mut self)
+        {
+            self.front.invalidate();
+        }
+
+        /// Sets whether the previously flushed frame is exposed to the
+        /// renderer, see [`Render::prev_char`].
+        #[inline]
+        pub fn keep_prev_frame(&mut self, keep: bool)
+        {
+            self.keep_prev = keep;
+        }
+
+        /// Sets the level of color support of the terminal.
+        ///
+        /// Colors that are not supported are converted to the nearest
+        /// supported ones when flushing, see [`Color::downgrade`]. Defaults to
+        /// [`ColorSupport::TrueColor`], i.e. all colors are written as they
+        /// are.
+        ///
+        /// [`Color::downgrade`]: crate::style::Color::downgrade
+        #[inline]
+        pub fn set_color_support(&mut self, level: ColorSupport)
+        {
+            self.colors = level;
+            self.front.invalidate();
+        }
+
+        /// Sets the character and style that each frame is cleared with
+        /// before rendering, see [`Buffer::set_clear`].
+        #[inline]
+        pub fn set_clear(&mut self, c: char, style: Style)
+        {
+            self.blank = StyledChar { content: c, style };
+        }
+
+        /// Sets the width of tab stops that each frame is rendered with, see
+        /// [`Buffer::set_tab_width`].
+        #[inline]
+        pub fn set_tab_width(&mut self, width: u16)
+        {
+            self.tab_width = width;
+        }
+
+        /// Gets a reference to the writer.
+        #[inline]
+        pub fn writer(&self) -> &W
+        {
+            &self.writer
+        }
+
+        /// Gets a mutable reference to the writer.
+        #[inline]
+        pub fn writer_mut(&mut self) -> &mut W
+        {
+            &mut self.writer
+        }
+    }
+
+    impl<W: Write> Backend for WriterBackend<W> {
+        type Renderer<'r> = Buffer<'r>;
+        type FlushError = std::io::Error;
+
+        fn render<'a, 'r, F>(&'a mut self, ui: F)
+        where
+            F: FnOnce(&mut Self::Renderer<'r>),
+            'a: 'r
+        {
+            let mut buffer = Buffer::new(
+                self.width,
+                self.height,
+                &mut self.chars,
+                &mut self.styles,
+                &mut self.cursor
+            );
+            if self.keep_prev {
+                if let Some(prev) = self.front.frame(self.width, self.height) {
+                    buffer = buffer.with_prev(prev);
+                }
+            }
+            buffer.set_clear(self.blank.content, self.blank.style);
+            buffer.set_tab_width(self.tab_width);
+            buffer.clear();
+
+            ui(&mut buffer);
+        }
+
+        fn flush(&mut self) -> Result<(), Self::FlushError>
+        {
+            let buffer = Buffer::new(
+                self.width,
+                self.height,
+                &mut self.chars,
+                &mut self.styles,
+                &mut self.cursor
+            );
+
+            flush_buf(&mut self.writer, &buffer, &mut self.front, self.colors)
+        }
+    }
+
+    impl<W: Write> Drop for WriterBackend<W> {
+        fn drop(&mut self)
+        {
+            if self.restore_on_drop {
+                let _ = restore_terminal(
+                    &mut self.writer,
+                    &mut self.front,
+                    self.height
+                );
+            }
+        }
+    }
+
+    /// The previously flushed frame (the *front buffer*).
+    ///
+    /// Used to flush only the cells that changed since the last flush.
+    struct Front {
+        width: u16,
+        height: u16,
+        cells: Vec<(char, Style)>,
+        /// Position of the terminal cursor, if it was left shown there by the
+        /// last flush.
+        cursor: Option<Pos>,
+        /// Whether the frame matches the contents of the screen.
+        valid: bool,
+    }
+
+    impl Front {
+        fn new() -> Self
+        {
+            Self {
+                width: 0,
+                height: 0,
+                cells: Vec::new(),
+                cursor: None,
+                valid: false,
+            }
+        }
+
+        /// Forces the next flush to rewrite the whole screen.
+        #[inline]
+        fn invalidate(&mut self)
+        {
+            self.valid = false;
+        }
+
+        /// Checks if the cell at `idx` has changed in `buffer`.
+        #[inline]
+        fn changed(&self, buffer: &Buffer, idx: usize) -> bool
+        {
+            let (c, style) = self.cells[idx];
+
+            c != buffer.chars[idx]
+                || Sgr::from(style) != Sgr::from(buffer.styles[idx])
+        }
+
+        /// Gets the cells of the frame, if it is valid and of the given
+        /// dimensions.
+        #[inline]
+        fn frame(&self, width: u16, height: u16) -> Option<&[(char, Style)]>
+        {
+            (self.valid && self.width == width && self.height == height)
+                .then_some(&self.cells)
+        }
+
+        fn store(&mut self, buffer: &Buffer)
+        {
+            let size = buffer.width as usize * buffer.height as usize;
+
+            self.width = buffer.width;
+            self.height = buffer.height;
+            self.cells.clear();
+            self.cells.extend(
+                buffer.chars[..size].iter()
+                    .copied()
+                    .zip(buffer.styles[..size].iter().copied())
+            );
+            self.cursor = if buffer.cursor.hidden
+                { None }
+                else { Some(Pos { x: buffer.cursor.x, y: buffer.cursor.y }) };
+            self.valid = true;
+        }
+    }
+
+    /// Flushes `buffer`, rewriting only the cells that differ from `front`.
+    ///
+    /// The whole screen is rewritten when `front` is not valid or has
+    /// different dimensions. Nothing is written if `buffer` has no cells.
+    fn flush_buf<W: Write>(
+        writer: &mut W,
+        buffer: &Buffer,
+        front: &mut Front,
+        colors: ColorSupport
+    ) -> Result<(), std::io::Error>
+    {
+        if buffer.width == 0 || buffer.height == 0 {
+            return Ok(());
+        }
+
+        // The whole frame is assembled first and written at once, instead of
+        // issuing a write for every character and escape sequence.
+        let size = buffer.width as usize * buffer.height as usize;
+        let mut out = Vec::with_capacity(size * 2);
+
+        park_cursor(&mut out, front)?;
+        if front.valid
+            && front.width == buffer.width
+            && front.height == buffer.height
+        {
+            write_diff(&mut out, buffer, front, colors)?;
+        } else {
+            write_buf(&mut out, buffer, colors)?;
+        }
+        write_cursor(&mut out, buffer)?;
+
+        writer.write_all(&out)?;
+        writer.flush()?;
+
+        front.store(buffer);
+
+        Ok(())
+    }
+
+    fn write_buf<W: Write>(
+        writer: &mut W,
+        buffer: &Buffer,
+        colors: ColorSupport
+    ) -> Result<(), std::io::Error>
     {
         for y in 0..buffer.height - 1 {
-            write_line(writer, &buffer, y)?;
+            write_line(writer, buffer, y, colors)?;
             console::write_str(writer, "\r\n")?;
         }
 
-        write_line(writer, &buffer, buffer.height - 1)?;
+        write_line(writer, buffer, buffer.height - 1, colors)?;
         console::write_char(writer, '\r')?;
         console::move_cursor(writer, -(buffer.height as isize - 1), 0)?;
 
-        // TODO: implement cursor with a real cursor.
+        Ok(())
+    }
+
+    /// Writes the runs of cells that differ from `front`.
+    ///
+    /// Expects the cursor to be at the top left of the screen and moves it
+    /// back there after each run.
+    fn write_diff<W: Write>(
+        writer: &mut W,
+        buffer: &Buffer,
+        front: &Front,
+        colors: ColorSupport
+    ) -> Result<(), std::io::Error>
+    {
+        let width = buffer.width as usize;
+
+        for y in 0..buffer.height as usize {
+            let row = offset!(0, y, width);
+            let mut x = 0;
+
+            while x < width {
+                if !front.changed(buffer, row + x) {
+                    x += 1;
+                    continue;
+                }
+
+                let mut start = x;
+                let mut end = x + 1;
+                while end < width && front.changed(buffer, row + end) {
+                    end += 1;
+                }
+
+                // Wide characters (old or new) are rewritten together with the
+                // cells they cover.
+                let is_wide = |idx: usize| {
+                    char_width(buffer.chars[idx]) > 1
+                        || char_width(front.cells[idx].0) > 1
+                };
+                if start > 0 && is_wide(row + start - 1) {
+                    start -= 1;
+                }
+                if end < width && is_wide(row + end - 1) {
+                    end += 1;
+                }
+
+                console::move_cursor(writer, y as isize, start as isize)?;
+                write_cells(
+                    writer,
+                    &buffer.chars[row + start..row + end],
+                    &buffer.styles[row + start..row + end],
+                    colors
+                )?;
+                console::write_char(writer, '\r')?;
+                console::move_cursor(writer, -(y as isize), 0)?;
+
+                x = end;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Shows the terminal cursor at its position, if it is not hidden.
+    ///
+    /// Expects the cursor to be at the top left of the screen and leaves it at
+    /// its position, see [`park_cursor`].
+    fn write_cursor<W: Write>(writer: &mut W, buffer: &Buffer)
+        -> Result<(), std::io::Error>
+    {
         if !buffer.cursor.hidden {
-            // Move the cursor to the its position.
+            console::set_cursor_shape(writer, buffer.cursor.shape)?;
             console::move_cursor(
                 writer,
                 buffer.cursor.y as isize,
                 buffer.cursor.x as isize
             )?;
-            // char printing
-            console::add_text_style(writer, TextStyle::INVERT)?;
-            console::write_char(
-                writer,
-                buffer.chars[offset!(
-                    buffer.cursor.x,
-                    buffer.cursor.y,
-                    buffer.width
-                )]
-            )?;
-            console::subtract_text_style(writer, TextStyle::INVERT)?;
-            console::move_cursor(writer, 0, -1)?;
-            // Move the cursor back to the top left of the screen.
-            console::move_cursor(
-                writer,
-                -(buffer.cursor.y as isize),
-                -(buffer.cursor.x as isize)
-            )?;
+            console::show_cursor(writer)?;
         }
 
-        writer.flush()?;
-
         Ok(())
     }
 
-    fn write_line<W: Write>(writer: &mut W, buffer: &Buffer<'_>, y: u16)
+    /// Hides the terminal cursor and moves it back to the top left of the
+    /// screen, if it was left shown by the last flush.
+    fn park_cursor<W: Write>(writer: &mut W, front: &mut Front)
         -> Result<(), std::io::Error>
+    {
+        if let Some(pos) = front.cursor.take() {
+            console::hide_cursor(writer)?;
+            console::move_cursor(writer, -(pos.y as isize), -(pos.x as isize))?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn write_line<W: Write>(
+        writer: &mut W,
+        buffer: &Buffer<'_>,
+        y: u16,
+        colors: ColorSupport
+    ) -> Result<(), std::io::Error>
     {
         let width = buffer.width as usize;
         let line_offset = offset!(0, y, width);
         let chars = &buffer.chars[line_offset..line_offset + width];
         let styles = &buffer.styles[line_offset..line_offset + width];
 
-        let mut saved_ts = styles[0].text_style.unwrap_or_default();
-        let mut saved_fg = styles[0].fg_color.unwrap_or_default();
-        let mut saved_bg = styles[0].bg_color.unwrap_or_default();
-        // The first char of every line is always set with colors and style.
-        console::reset(writer)?;
-        console::set_text_style(writer, saved_ts)?;
-        console::set_fg_color(writer, saved_fg)?;
-        console::set_bg_color(writer, saved_bg)?;
-        console::write_char(writer, chars[0])?;
+        write_cells(writer, chars, styles, colors)
+    }
 
-        for x in 1..width {
-            let cur_style = &styles[x];
-            let cur_char = &chars[x];
+    /// Writes a run of cells.
+    ///
+    /// The first cell is always set with colors and style. The colors are
+    /// downgraded to `colors`.
+    fn write_cells<W: Write>(
+        writer: &mut W,
+        chars: &[char],
+        styles: &[Style],
+        colors: ColorSupport
+    ) -> Result<(), std::io::Error>
+    {
+        let mut saved = Sgr::from(styles[0]).downgrade(colors);
+        console::set_sgr(writer, saved)?;
+        console::write_char(writer, chars[0])?;
 
-            let text_style = cur_style.text_style.unwrap_or_default();
-            let fg_color = cur_style.fg_color.unwrap_or_default();
-            let bg_color = cur_style.bg_color.unwrap_or_default();
+        // The cell following a wide character is covered by it.
+        let mut covered = char_width(chars[0]) > 1;
 
-            let ts_changed = saved_ts != text_style;
-            if ts_changed {
-                console::reset(writer)?;
-                console::add_text_style(writer, text_style)?;
-                saved_ts = text_style;
+        for x in 1..chars.len() {
+            if covered {
+                covered = false;
+                continue;
             }
 
-            if saved_fg != fg_color || ts_changed {
-                console::set_fg_color(writer, fg_color)?;
-                saved_fg = fg_color;
-            }
-            if saved_bg != bg_color || ts_changed {
-                console::set_bg_color(writer, bg_color)?;
-                saved_bg = bg_color;
+            let cur_char = chars[x];
+            covered = char_width(cur_char) > 1;
+
+            let sgr = Sgr::from(styles[x]).downgrade(colors);
+            if sgr != saved {
+                console::set_sgr(writer, sgr)?;
+                saved = sgr;
             }
 
-            console::write_char(writer, *cur_char)?;
+            console::write_char(writer, cur_char)?;
         }
 
         Ok(())
     }
 
-    fn restore_terminal<W: Write>(stdout: &mut W, last_height: u16)
-        -> std::io::Result<()>
+    fn restore_terminal<W: Write>(
+        stdout: &mut W,
+        front: &mut Front,
+        last_height: u16
+    ) -> std::io::Result<()>
     {
-            console::set_fg_color(stdout, Color::Normal)?;
-            console::set_bg_color(stdout, Color::Normal)?;
-            console::set_text_style(stdout, TextStyle::NORMAL)?;
+            park_cursor(stdout, front)?;
+            console::set_sgr(stdout, Sgr::default())?;
+            console::reset_cursor_shape(stdout)?;
             for _row in 0..last_height {
                 console::write_char(stdout, '\n')?;
             }
             console::show_cursor(stdout)
     }
+
+    fn restore_alt_screen<W: Write>(stdout: &mut W) -> std::io::Result<()>
+    {
+        console::set_sgr(stdout, Sgr::default())?;
+        console::reset_cursor_shape(stdout)?;
+        console::show_cursor(stdout)?;
+        console::leave_alt_screen(stdout)?;
+        stdout.flush()
+    }
 }
 
 mod console {
     use std::io::Write;
 
-    use crate::style::{Color, TextStyle};
+    use crate::backend::sgr::Sgr;
+    use crate::render::CursorShape;
 
     #[inline]
     pub fn write_char<W: Write>(writer: &mut W, c: char)
@@ -339,196 +970,71 @@ mod console {
     }
 
     #[inline]
-    pub fn move_cursor<W: Write>(writer: &mut W, y: isize, x: isize)
+    pub fn set_cursor_shape<W: Write>(writer: &mut W, shape: CursorShape)
         -> Result<(), std::io::Error>
     {
-        // NOTE: it has to be checked for zero values, as supplying 0 to the
-        // termion's cursor movement functions will result in the cursor being
-        // moved by one position.
-
-        // y movement
-        if y != 0 {
-            if y < 0 {
-                write!(writer, "{}", termion::cursor::Up((-y) as u16))?;
-            } else {
-                write!(writer, "{}", termion::cursor::Down(y as u16))?;
-            }
-        }
-        // x movement
-        if x != 0 {
-            if x < 0 {
-                write!(writer, "{}", termion::cursor::Left((-x) as u16))?;
-            } else {
-                write!(writer, "{}", termion::cursor::Right(x as u16))?;
-            }
-        }
-
-        Ok(())
-    }
-
-    #[inline]
-    pub fn reset<W: Write>(writer: &mut W) -> Result<(), std::io::Error>
-    {
-        write!(writer, "{}", termion::style::Reset)
+        write!(writer, "\x1b[{} q", shape.decscusr())
     }
 
+    /// Restores the shape of the cursor configured in the terminal.
     #[inline]
-    pub fn set_fg_color<W: Write>(writer: &mut W, color: Color)
+    pub fn reset_cursor_shape<W: Write>(writer: &mut W)
         -> Result<(), std::io::Error>
     {
-        use termion::color::*;
-        use crate::style::Color;
-
-        match color {
-            Color::Normal       => write!(writer, "{}", Fg(Reset))?,
-            Color::Black        => write!(writer, "{}", Fg(Black))?,
-            Color::Red          => write!(writer, "{}", Fg(Red))?,
-            Color::Green        => write!(writer, "{}", Fg(Green))?,
-            Color::Yellow       => write!(writer, "{}", Fg(Yellow))?,
-            Color::Blue         => write!(writer, "{}", Fg(Blue))?,
-            Color::Magenta      => write!(writer, "{}", Fg(Magenta))?,
-            Color::Cyan         => write!(writer, "{}", Fg(Cyan))?,
-            Color::White        => write!(writer, "{}", Fg(White))?,
-            Color::LightBlack   => write!(writer, "{}", Fg(LightBlack))?,
-            Color::LightRed     => write!(writer, "{}", Fg(LightRed))?,
-            Color::LightGreen   => write!(writer, "{}", Fg(LightGreen))?,
-            Color::LightYellow  => write!(writer, "{}", Fg(LightYellow))?,
-            Color::LightBlue    => write!(writer, "{}", Fg(LightBlue))?,
-            Color::LightMagenta => write!(writer, "{}", Fg(LightMagenta))?,
-            Color::LightCyan    => write!(writer, "{}", Fg(LightCyan))?,
-            Color::LightWhite   => write!(writer, "{}", Fg(LightWhite))?,
-            Color::Ansi(c)      => write!(writer, "{}", Fg(AnsiValue(c)))?,
-            Color::Rgb(r, g, b) => write!(writer, "{}", Fg(Rgb(r, g, b)))?,
-        }
-
-        Ok(())
+        write!(writer, "\x1b[0 q")
     }
 
     #[inline]
-    pub fn set_bg_color<W: Write>(writer: &mut W, color: Color)
+    pub fn enter_alt_screen<W: Write>(writer: &mut W)
         -> Result<(), std::io::Error>
     {
-        use termion::color::*;
-        use crate::style::Color;
-
-        match color {
-            Color::Normal       => write!(writer, "{}", Bg(Reset))?,
-            Color::Black        => write!(writer, "{}", Bg(Black))?,
-            Color::Red          => write!(writer, "{}", Bg(Red))?,
-            Color::Green        => write!(writer, "{}", Bg(Green))?,
-            Color::Yellow       => write!(writer, "{}", Bg(Yellow))?,
-            Color::Blue         => write!(writer, "{}", Bg(Blue))?,
-            Color::Magenta      => write!(writer, "{}", Bg(Magenta))?,
-            Color::Cyan         => write!(writer, "{}", Bg(Cyan))?,
-            Color::White        => write!(writer, "{}", Bg(White))?,
-            Color::LightBlack   => write!(writer, "{}", Bg(LightBlack))?,
-            Color::LightRed     => write!(writer, "{}", Bg(LightRed))?,
-            Color::LightGreen   => write!(writer, "{}", Bg(LightGreen))?,
-            Color::LightYellow  => write!(writer, "{}", Bg(LightYellow))?,
-            Color::LightBlue    => write!(writer, "{}", Bg(LightBlue))?,
-            Color::LightMagenta => write!(writer, "{}", Bg(LightMagenta))?,
-            Color::LightCyan    => write!(writer, "{}", Bg(LightCyan))?,
-            Color::LightWhite   => write!(writer, "{}", Bg(LightWhite))?,
-            Color::Ansi(c)      => write!(writer, "{}", Bg(AnsiValue(c)))?,
-            Color::Rgb(r, g, b) => write!(writer, "{}", Bg(Rgb(r, g, b)))?,
-        }
-
-        Ok(())
+        write!(
+            writer,
+            "{}{}",
+            termion::screen::ToAlternateScreen,
+            termion::cursor::Goto(1, 1)
+        )
     }
 
     #[inline]
-    pub fn set_text_style<W: Write>(writer: &mut W, ts: TextStyle)
+    pub fn leave_alt_screen<W: Write>(writer: &mut W)
         -> Result<(), std::io::Error>
     {
-        if ts.contains(TextStyle::BOLD) {
-            write!(writer, "{}", termion::style::Bold)?;
-        } else {
-            write!(writer, "{}", termion::style::NoBold)?;
-        }
-
-        if ts.contains(TextStyle::BLINK) {
-            write!(writer, "{}", termion::style::Blink)?;
-        } else {
-            write!(writer, "{}", termion::style::NoBlink)?;
-        }
-
-        if ts.contains(TextStyle::INVERT) {
-            write!(writer, "{}", termion::style::Invert)?;
-        } else {
-            write!(writer, "{}", termion::style::NoInvert)?;
-        }
-
-        if ts.contains(TextStyle::ITALIC) {
-            write!(writer, "{}", termion::style::Italic)?;
-        } else {
-            write!(writer, "{}", termion::style::NoItalic)?;
-        }
-
-        if ts.contains(TextStyle::UNDERLINE) {
-            write!(writer, "{}", termion::style::Underline)?;
-        } else {
-            write!(writer, "{}", termion::style::NoUnderline)?;
-        }
-
-        Ok(())
+        write!(writer, "{}", termion::screen::ToMainScreen)
     }
 
     #[inline]
-    pub fn add_text_style<W: Write>(writer: &mut W, ts: TextStyle)
+    pub fn move_cursor<W: Write>(writer: &mut W, y: isize, x: isize)
         -> Result<(), std::io::Error>
     {
-        if ts.contains(TextStyle::BOLD) {
-            write!(writer, "{}", termion::style::Bold)?;
-        }
-
-        if ts.contains(TextStyle::BLINK) {
-            write!(writer, "{}", termion::style::Blink)?;
-        }
-
-        if ts.contains(TextStyle::INVERT) {
-            write!(writer, "{}", termion::style::Invert)?;
-        }
+        // NOTE: it has to be checked for zero values, as supplying 0 to the
+        // termion's cursor movement functions will result in the cursor being
+        // moved by one position.
 
-        if ts.contains(TextStyle::ITALIC) {
-            write!(writer, "{}", termion::style::Italic)?;
+        // y movement
+        if y != 0 {
+            if y < 0 {
+                write!(writer, "{}", termion::cursor::Up((-y) as u16))?;
+            } else {
+                write!(writer, "{}", termion::cursor::Down(y as u16))?;
+            }
         }
-
-        if ts.contains(TextStyle::UNDERLINE) {
-            write!(writer, "{}", termion::style::Underline)?;
+        // x movement
+        if x != 0 {
+            if x < 0 {
+                write!(writer, "{}", termion::cursor::Left((-x) as u16))?;
+            } else {
+                write!(writer, "{}", termion::cursor::Right(x as u16))?;
+            }
         }
 
         Ok(())
     }
 
-    /// BUG: doesn't work for all attributes, mainly `TextStyle::BOLD`.
-    ///
-    /// This function should be completelty deprecated, as not all of these are
-    /// supported universally. In such cases, the only way to undo a style is to
-    /// do a full reset, which affects colors too.
     #[inline]
-    pub fn subtract_text_style<W: Write>(writer: &mut W, ts: TextStyle)
+    pub fn set_sgr<W: Write>(writer: &mut W, sgr: Sgr)
         -> Result<(), std::io::Error>
     {
-        if ts.contains(TextStyle::BOLD) {
-            write!(writer, "{}", termion::style::NoBold)?;
-        }
-
-        if ts.contains(TextStyle::BLINK) {
-            write!(writer, "{}", termion::style::NoBlink)?;
-        }
-
-        if ts.contains(TextStyle::INVERT) {
-            write!(writer, "{}", termion::style::NoInvert)?;
-        }
-
-        if ts.contains(TextStyle::ITALIC) {
-            write!(writer, "{}", termion::style::NoItalic)?;
-        }
-
-        if ts.contains(TextStyle::UNDERLINE) {
-            write!(writer, "{}", termion::style::NoUnderline)?;
-        }
-
-        Ok(())
+        write!(writer, "{}", sgr)
     }
 }
@@ -0,0 +1,183 @@
+use bitflags::bitflags;
+
+use crate::layout::{Area, Pos};
+use crate::render::Render;
+use crate::style::{Style, StyledChar};
+
+
+bitflags! {
+    /// Edges of a cell that are connected by a box-drawing line.
+    #[derive(Default)]
+    pub struct Edges: u8 {
+        const NONE  = 0b0000;
+        const UP    = 0b0001;
+        const RIGHT = 0b0010;
+        const DOWN  = 0b0100;
+        const LEFT  = 0b1000;
+    }
+}
+
+/// Light box-drawing characters, indexed by the bits of [`Edges`].
+const GLYPHS: [char; 16] = [
+    ' ', '╵', '╶', '└',
+    '╷', '│', '┌', '├',
+    '╴', '┘', '─', '┴',
+    '┐', '┤', '┬', '┼',
+];
+
+impl Edges {
+    /// Gets the edges of a box-drawing character.
+    ///
+    /// Light lines with rounded corners are recognized as well. Returns
+    /// `None` for other characters.
+    pub fn from_char(c: char) -> Option<Self>
+    {
+        let bits = match c {
+            '╭' => return Some(Self::RIGHT | Self::DOWN),
+            '╮' => return Some(Self::LEFT | Self::DOWN),
+            '╯' => return Some(Self::LEFT | Self::UP),
+            '╰' => return Some(Self::RIGHT | Self::UP),
+            ' ' => return None,
+            c => GLYPHS.iter().position(|&g| g == c)?,
+        };
+
+        Self::from_bits(bits as u8)
+    }
+
+    /// Gets the light box-drawing character for the edges.
+    ///
+    /// No edges map to a space.
+    #[inline]
+    pub fn to_char(self) -> char
+    {
+        GLYPHS[self.bits() as usize]
+    }
+}
+
+
+/// Draws box-drawing lines onto a [`Render`], connecting lines that meet.
+///
+/// When a line is drawn over a cell that already contains a box-drawing
+/// character, the edges of both are combined, so that the correct junction
+/// character is written (e.g. `┬`, `├` or `┼`). The existing cells are read
+/// back with [`Render::get`], so lines are not connected on renderers that do
+/// not support reading back cells.
+///
+/// Cells outside of the paint area are skipped.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::boxdraw::BoxDrawer;
+/// use cwinui::render::Render;
+/// use cwinui::layout::Pos;
+///
+/// let mut backend = MemBackend::new(5, 3);
+///
+/// backend.render(|buf| {
+///     let area = buf.area();
+///     let mut boxes = BoxDrawer::new(buf);
+///     boxes.rect(area);
+///     boxes.vline(Pos { x: 2, y: 0 }, 3);
+///     boxes.hline(Pos { x: 2, y: 1 }, 3);
+/// });
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "┌─┬─┐\n│ ├─┤\n└─┴─┘");
+/// ```
+#[derive(Debug)]
+pub struct BoxDrawer<'a, R: Render> {
+    pub style: Style,
+    buf: &'a mut R,
+}
+
+impl<'a, R: Render> BoxDrawer<'a, R> {
+    /// Creates a new `BoxDrawer` drawing onto `buf`.
+    #[inline]
+    pub fn new(buf: &'a mut R) -> Self
+    {
+        Self {
+            buf,
+            style: Style::default(),
+        }
+    }
+
+    /// Adjusts the style of the lines.
+    #[inline]
+    pub fn style(mut self, style: Style) -> Self
+    {
+        self.style = style;
+
+        self
+    }
+
+    /// Adds `edges` to the cell at `pos`.
+    pub fn add(&mut self, pos: Pos, edges: Edges)
+    {
+        let area = self.buf.area();
+
+        if edges.is_empty() || pos.x >= area.width || pos.y >= area.height {
+            return;
+        }
+
+        let existing = self.buf.get(pos)
+            .and_then(|c| Edges::from_char(c.content))
+            .unwrap_or_default();
+
+        self.buf.set_char(pos, StyledChar {
+            content: (existing | edges).to_char(),
+            style: self.style,
+        });
+    }
+
+    /// Draws a horizontal line of length `len`, starting at `pos`.
+    pub fn hline(&mut self, pos: Pos, len: u16)
+    {
+        for i in 0..len {
+            let mut edges = Edges::NONE;
+            if i > 0 {
+                edges |= Edges::LEFT;
+            }
+            if i + 1 < len {
+                edges |= Edges::RIGHT;
+            }
+
+            let Some(x) = pos.x.checked_add(i) else { break };
+            self.add(Pos { x, y: pos.y }, edges);
+        }
+    }
+
+    /// Draws a vertical line of length `len`, starting at `pos`.
+    pub fn vline(&mut self, pos: Pos, len: u16)
+    {
+        for i in 0..len {
+            let mut edges = Edges::NONE;
+            if i > 0 {
+                edges |= Edges::UP;
+            }
+            if i + 1 < len {
+                edges |= Edges::DOWN;
+            }
+
+            let Some(y) = pos.y.checked_add(i) else { break };
+            self.add(Pos { x: pos.x, y }, edges);
+        }
+    }
+
+    /// Draws the outline of `area`.
+    pub fn rect(&mut self, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let bottom = area.y + area.height - 1;
+        let right = area.x + area.width - 1;
+
+        self.hline(area.top_left(), area.width);
+        self.hline(Pos { x: area.x, y: bottom }, area.width);
+        self.vline(area.top_left(), area.height);
+        self.vline(Pos { x: right, y: area.y }, area.height);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::render::Render;
+use crate::render::{CursorShape, Render};
 use crate::{Pos, Area};
 use crate::style::{AsStyledStr, Style, StyledChar};
-use crate::util::offset;
+use crate::util::{offset, char_width};
 
 /// Internals determining the state of the cursor.
 #[derive(Debug, Clone, Copy)]
@@ -9,6 +9,21 @@ pub(crate) struct Cursor {
     pub x: u16,
     pub y: u16,
     pub hidden: bool,
+    pub shape: CursorShape,
+}
+
+impl Cursor {
+    /// Creates a hidden cursor at the top left corner.
+    #[inline]
+    pub const fn new() -> Self
+    {
+        Self {
+            x: 0,
+            y: 0,
+            hidden: true,
+            shape: CursorShape::TerminalDefault,
+        }
+    }
 }
 
 /// Versatile container-agnostic buffer that can be used for painting widgets.
@@ -19,6 +34,11 @@ pub struct Buffer<'a> {
     pub(crate) chars: &'a mut [char],
     pub(crate) styles: &'a mut [Style],
     pub(crate) cursor: &'a mut Cursor,
+    pub(crate) tab_width: u16,
+    /// Cells of the previously presented frame, if retained.
+    pub(crate) prev: Option<&'a [(char, Style)]>,
+    /// Cell used for clearing.
+    pub(crate) blank: StyledChar,
 }
 
 impl<'a> Buffer<'a> {
@@ -44,8 +64,50 @@ impl<'a> Buffer<'a> {
             chars,
             styles,
             cursor,
+            tab_width: 8,
+            prev: None,
+            blank: StyledChar { content: ' ', style: Style::default() },
         }
     }
+
+    /// Attaches the cells of the previously presented frame.
+    ///
+    /// # Panics
+    ///
+    /// If the length of `prev` is less than `width * height`.
+    pub(crate) fn with_prev(mut self, prev: &'a [(char, Style)]) -> Self
+    {
+        assert!(prev.len() >= self.width as usize * self.height as usize);
+
+        self.prev = Some(prev);
+
+        self
+    }
+
+    /// Sets the width of tab stops used for expanding `'\t'`.
+    ///
+    /// The width is at least `1`.
+    #[inline]
+    pub fn set_tab_width(&mut self, width: u16)
+    {
+        self.tab_width = std::cmp::max(width, 1);
+    }
+
+    /// Sets the character and style that the buffer is cleared with, see
+    /// [`Render::clear`].
+    ///
+    /// Defaults to `' '` with [`Style::default`]. The character has to be one
+    /// column wide and not a control character, others are replaced with
+    /// `' '`.
+    #[inline]
+    pub fn set_clear(&mut self, c: char, style: Style)
+    {
+        let c = if char_width(c) == 1 && !c.is_control()
+            { c }
+            else { ' ' };
+
+        self.blank = StyledChar { content: c, style };
+    }
 }
 
 impl Render for Buffer<'_> {
@@ -69,21 +131,54 @@ impl Render for Buffer<'_> {
 
         let text = text.as_styled_str();
 
-        // TODO: support printing with newlines (and other non-standard
-        // whitespace).
-        // FIXME: check for variable-length characters.
-        // FIXME: check for non-printable characters.
+        // NOTE: newlines are not interpreted here, see
+        // `Render::print_multiline`.
+
+        let mut col = x;
+
+        for c in text.content.chars() {
+            if c == '\t' {
+                // Tab stops are relative to the start of the string.
+                let tab_width = self.tab_width as usize;
+                let next = x + ((col - x) / tab_width + 1) * tab_width;
 
-        // TODO: utf8 support.
+                for col in col..std::cmp::min(next, w) {
+                    let offset = offset!(col, y, w);
 
-        let mut chars = text.content.chars();
+                    self.chars[offset] = ' ';
+                    let style = &mut self.styles[offset];
+                    *style = style.merge(text.style);
+                }
+
+                col = next;
+                continue;
+            }
 
-        for i in 0..text.content.len() {
-            let offset = offset!(x + i, y, w);
+            // Control characters would corrupt the output.
+            let c = if c.is_control() { ' ' } else { c };
+            let width = char_width(c);
 
-            self.chars[offset] = chars.next().unwrap();
+            // NOTE: zero-width characters (e.g. combining marks) cannot be
+            // represented in a single cell.
+            if width == 0 {
+                continue;
+            }
+
+            let offset = offset!(col, y, w);
+
+            self.chars[offset] = c;
             let style = &mut self.styles[offset];
             *style = style.merge(text.style);
+
+            // The cell covered by the second half of a wide character is
+            // skipped when flushing, only its style is kept consistent.
+            if width > 1 && col + 1 < w {
+                self.chars[offset + 1] = ' ';
+                let style = &mut self.styles[offset + 1];
+                *style = style.merge(text.style);
+            }
+
+            col += width;
         }
     }
 
@@ -100,12 +195,56 @@ impl Render for Buffer<'_> {
         *style = style.merge(c.style);
     }
 
+    #[inline]
+    fn set_style(&mut self, pos: Pos, style: Style)
+    {
+        let idx = offset!(pos.x, pos.y, self.width);
+        let cell_style = &mut self.styles[idx];
+        *cell_style = cell_style.merge(style);
+    }
+
     #[inline]
     fn clear(&mut self)
     {
-        self.chars.fill(' ');
-        self.styles.fill(Style::default());
-        *self.cursor = Cursor { x: 0, y: 0, hidden: true };
+        self.chars.fill(self.blank.content);
+        self.styles.fill(self.blank.style);
+        *self.cursor = Cursor::new();
+    }
+
+    #[inline]
+    fn blank(&self) -> StyledChar
+    {
+        self.blank
+    }
+
+    #[inline]
+    fn tab_width(&self) -> u16
+    {
+        self.tab_width
+    }
+
+    #[inline]
+    fn get(&self, pos: Pos) -> Option<StyledChar>
+    {
+        if pos.x >= self.width || pos.y >= self.height {
+            return None;
+        }
+
+        let idx = offset!(pos.x, pos.y, self.width);
+
+        Some(StyledChar { content: self.chars[idx], style: self.styles[idx] })
+    }
+
+    #[inline]
+    fn prev_char(&self, pos: Pos) -> Option<StyledChar>
+    {
+        if pos.x >= self.width || pos.y >= self.height {
+            return None;
+        }
+
+        let (content, style) = self.prev?[offset!(pos.x, pos.y, self.width)];
+
+        Some(StyledChar { content, style })
     }
 
     #[inline]
@@ -131,6 +270,12 @@ impl Render for Buffer<'_> {
         self.cursor.y = pos.y;
     }
 
+    #[inline]
+    fn set_cursor_shape(&mut self, shape: CursorShape)
+    {
+        self.cursor.shape = shape;
+    }
+
     fn hfill<C: Into<StyledChar>>(&mut self, pos: Pos, c: C, len: usize)
     {
         let dim = self.dimensions();
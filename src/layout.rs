@@ -140,6 +140,31 @@ impl std::ops::Sub for Pos {
     }
 }
 
+impl From<(u16, u16)> for Pos {
+    /// Converts from `(x, y)`.
+    ///
+    /// ```
+    /// use cwinui::Pos;
+    ///
+    /// assert_eq!(Pos::from((3, 4)), Pos { x: 3, y: 4 });
+    /// assert_eq!(<(u16, u16)>::from(Pos { x: 3, y: 4 }), (3, 4));
+    /// ```
+    #[inline]
+    fn from((x, y): (u16, u16)) -> Self
+    {
+        Self { x, y }
+    }
+}
+
+impl From<Pos> for (u16, u16) {
+    /// Converts to `(x, y)`.
+    #[inline]
+    fn from(pos: Pos) -> Self
+    {
+        (pos.x, pos.y)
+    }
+}
+
 /// Area dimensions.
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, std::hash::Hash)]
 pub struct Dim {
@@ -200,6 +225,33 @@ impl Dim {
     }
 }
 
+impl From<(u16, u16)> for Dim {
+    /// Converts from `(width, height)`.
+    ///
+    /// ```
+    /// use cwinui::Dim;
+    ///
+    /// assert_eq!(Dim::from((80, 24)), Dim { width: 80, height: 24 });
+    ///
+    /// let (width, height) = Dim { width: 80, height: 24 }.into();
+    /// assert_eq!((width, height), (80, 24));
+    /// ```
+    #[inline]
+    fn from((width, height): (u16, u16)) -> Self
+    {
+        Self { width, height }
+    }
+}
+
+impl From<Dim> for (u16, u16) {
+    /// Converts to `(width, height)`.
+    #[inline]
+    fn from(dim: Dim) -> Self
+    {
+        (dim.width, dim.height)
+    }
+}
+
 /// Proportions of widgets that can be laid out in space.
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, std::hash::Hash)]
 pub struct Proportions {
@@ -285,6 +337,18 @@ impl Proportions {
             height: self.height.join(other.height),
         }
     }
+
+    /// Scales the range requirements for both directions by `factor`.
+    ///
+    /// See [`Range::scale`] for the rounding behaviour.
+    #[inline]
+    pub fn scale(self, factor: f32) -> Self
+    {
+        Self {
+            width: self.width.scale(factor),
+            height: self.height.scale(factor),
+        }
+    }
 }
 
 /// Inclusive range of sizes.
@@ -510,6 +574,32 @@ impl Range {
             },
         }
     }
+
+    /// Scales the range by `factor`.
+    ///
+    /// Both the minimum and the maximum (if any) are multiplied by `factor` and
+    /// rounded half-up. The results saturate at `u16::MAX`, and negative or NaN
+    /// results become `0`.
+    ///
+    /// ```
+    /// use cwinui::layout::Range;
+    ///
+    /// assert_eq!(Range::new(3, 10).scale(2.0), Range::new(6, 20));
+    /// assert_eq!(Range::new(3, 10).scale(0.5), Range::new(2, 5));
+    /// assert_eq!(Range::from(3).scale(1.5), Range::from(5));
+    /// assert_eq!(Range::fixed(40_000).scale(2.0), Range::fixed(u16::MAX));
+    /// ```
+    #[inline]
+    pub fn scale(self, factor: f32) -> Self
+    {
+        // `as` saturates and maps NaN to 0.
+        let scale = |size: u16| (size as f32 * factor + 0.5) as u16;
+
+        Self {
+            min: scale(self.min),
+            max: self.max.map(scale),
+        }
+    }
 }
 
 /// Objects that have proportions.
@@ -534,6 +624,18 @@ impl<T: Proportional> Proportional for &mut T {
     }
 }
 
+impl<T: Proportional> Proportional for Option<T> {
+    /// The proportions of the inner widget, or [`Proportions::ZERO`] for
+    /// `None`.
+    fn proportions(&self) -> Proportions
+    {
+        match self {
+            Some(inner) => inner.proportions(),
+            None => Proportions::ZERO,
+        }
+    }
+}
+
 /// Rectangular area.
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct Area {
@@ -608,6 +710,29 @@ impl Area {
     }
 
     /// Checks if areas overlap.
+    ///
+    /// Areas that only touch along an edge or at a corner do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let area = Area { x: 0, y: 0, width: 5, height: 5 };
+    ///
+    /// // Touching edges.
+    /// assert!(!area.overlaps(Area { x: 5, y: 0, width: 5, height: 5 }));
+    /// assert!(!area.overlaps(Area { x: 0, y: 5, width: 5, height: 5 }));
+    /// // Touching corners.
+    /// assert!(!area.overlaps(Area { x: 5, y: 5, width: 5, height: 5 }));
+    /// // Sharing a single cell.
+    /// assert!(area.overlaps(Area { x: 4, y: 4, width: 5, height: 5 }));
+    /// // Containment, in both directions.
+    /// let inner = Area { x: 1, y: 1, width: 2, height: 2 };
+    /// assert!(area.overlaps(inner));
+    /// assert!(inner.overlaps(area));
+    /// assert!(area.overlaps(area));
+    /// ```
     pub const fn overlaps(&self, other: Self) -> bool
     {
         let other_l = other.x;
@@ -636,6 +761,140 @@ impl Area {
             && pos.y < self.y + self.height
     }
 
+    /// Checks if `other` lies entirely within the area.
+    ///
+    /// The right and bottom edges are exclusive, as with
+    /// [`Area::contains_pos`], so an area contains itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let area = Area { x: 2, y: 2, width: 6, height: 4 };
+    ///
+    /// assert!(area.contains_area(area));
+    /// assert!(area.contains_area(Area { x: 3, y: 3, width: 2, height: 2 }));
+    /// // Touching the right and bottom edges from the inside.
+    /// assert!(area.contains_area(Area { x: 5, y: 4, width: 3, height: 2 }));
+    /// // Sticking out by one cell.
+    /// assert!(!area.contains_area(Area { x: 5, y: 4, width: 4, height: 2 }));
+    /// assert!(!area.contains_area(Area { x: 1, y: 2, width: 2, height: 2 }));
+    /// // Touching from the outside.
+    /// assert!(!area.contains_area(Area { x: 8, y: 2, width: 2, height: 2 }));
+    /// ```
+    #[inline]
+    pub const fn contains_area(&self, other: Self) -> bool
+    {
+        other.x >= self.x
+            && other.x + other.width <= self.x + self.width
+            && other.y >= self.y
+            && other.y + other.height <= self.y + self.height
+    }
+
+    /// Clamps `pos` into the area.
+    ///
+    /// For a collapsed area, the corresponding coordinate is clamped to the
+    /// start of the area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::{Area, Pos};
+    ///
+    /// let area = Area { x: 2, y: 2, width: 6, height: 4 };
+    ///
+    /// assert_eq!(area.clamp_pos(Pos { x: 3, y: 3 }), Pos { x: 3, y: 3 });
+    /// assert_eq!(area.clamp_pos(Pos { x: 0, y: 9 }), Pos { x: 2, y: 5 });
+    /// assert_eq!(area.clamp_pos(Pos { x: 8, y: 0 }), Pos { x: 7, y: 2 });
+    /// ```
+    #[inline]
+    pub const fn clamp_pos(&self, pos: Pos) -> Pos
+    {
+        let right = self.x + self.width.saturating_sub(1);
+        let bottom = self.y + self.height.saturating_sub(1);
+
+        Pos {
+            x: min!(max!(pos.x, self.x), right),
+            y: min!(max!(pos.y, self.y), bottom),
+        }
+    }
+
+    /// Moves `inner` into the area, shrinking it if it is larger than the
+    /// area.
+    ///
+    /// `inner` is moved as little as possible, see also [`Area::align_to`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let screen = Area { x: 0, y: 0, width: 80, height: 24 };
+    ///
+    /// // A popup overflowing the right and bottom edges.
+    /// let popup = Area { x: 70, y: 20, width: 20, height: 6 };
+    /// assert_eq!(
+    ///     screen.clamp_area(popup),
+    ///     Area { x: 60, y: 18, width: 20, height: 6 }
+    /// );
+    ///
+    /// // A popup larger than the screen.
+    /// let popup = Area { x: 10, y: 0, width: 100, height: 4 };
+    /// assert_eq!(
+    ///     screen.clamp_area(popup),
+    ///     Area { x: 0, y: 0, width: 80, height: 4 }
+    /// );
+    /// ```
+    #[inline]
+    pub const fn clamp_area(&self, inner: Self) -> Self
+    {
+        let width = min!(inner.width, self.width);
+        let height = min!(inner.height, self.height);
+
+        Self {
+            x: min!(max!(inner.x, self.x), self.x + self.width - width),
+            y: min!(max!(inner.y, self.y), self.y + self.height - height),
+            width,
+            height,
+        }
+    }
+
+    /// Iterates over the positions of all the cells in the area, row by row.
+    ///
+    /// Yields nothing for a collapsed area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::{Area, Pos};
+    ///
+    /// let area = Area { x: 1, y: 2, width: 2, height: 2 };
+    /// let positions: Vec<Pos> = area.positions().collect();
+    ///
+    /// assert_eq!(positions, [
+    ///     Pos { x: 1, y: 2 },
+    ///     Pos { x: 2, y: 2 },
+    ///     Pos { x: 1, y: 3 },
+    ///     Pos { x: 2, y: 3 },
+    /// ]);
+    ///
+    /// let mut iter = area.positions();
+    /// iter.next();
+    /// assert_eq!(iter.len(), 3);
+    ///
+    /// let collapsed = Area { x: 1, y: 2, width: 0, height: 2 };
+    /// assert_eq!(collapsed.positions().count(), 0);
+    /// ```
+    #[inline]
+    pub const fn positions(&self) -> Positions
+    {
+        Positions {
+            area: *self,
+            next: self.top_left(),
+        }
+    }
+
     /// Checks if either of the dimensions is `0`.
     #[inline]
     pub const fn is_collapsed(&self) -> bool
@@ -645,7 +904,26 @@ impl Area {
 
     /// Computes the intersection of `self` and `other`.
     ///
-    /// **It is unsound to call this on areas that do not overlap!!!**
+    /// **It is unsound to call this on areas that do not overlap!!!** This
+    /// includes areas that only touch, see [`Area::overlaps`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let area = Area { x: 0, y: 0, width: 5, height: 5 };
+    /// let other = Area { x: 3, y: 1, width: 5, height: 2 };
+    ///
+    /// assert_eq!(
+    ///     area.intersection(other),
+    ///     Area { x: 3, y: 1, width: 2, height: 2 }
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, when `self` and `other` do not overlap.
     ///
     /// # Overflows
     ///
@@ -653,6 +931,8 @@ impl Area {
     #[inline]
     pub const fn intersection(&self, other: Self) -> Self
     {
+        debug_assert!(self.overlaps(other));
+
         let left_x   = max!(self.x, other.x);
         let right_x  = min!(self.x + self.width, other.x + other.width);
         let top_y    = max!(self.y, other.y);
@@ -666,8 +946,50 @@ impl Area {
         }
     }
 
+    /// Computes the smallest area covering both `self` and `other` (their
+    /// bounding box).
+    ///
+    /// Collapsed areas are not special-cased: their position is still
+    /// covered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let a = Area { x: 1, y: 1, width: 2, height: 2 };
+    /// let b = Area { x: 6, y: 4, width: 3, height: 1 };
+    ///
+    /// // Disjoint areas, the union spans the gap.
+    /// assert_eq!(a.union(b), Area { x: 1, y: 1, width: 8, height: 4 });
+    /// assert_eq!(b.union(a), a.union(b));
+    ///
+    /// // One contains the other.
+    /// let outer = Area { x: 0, y: 0, width: 10, height: 10 };
+    /// assert_eq!(outer.union(a), outer);
+    /// assert_eq!(a.union(outer), outer);
+    /// ```
+    #[inline]
+    pub const fn union(&self, other: Self) -> Self
+    {
+        let left_x   = min!(self.x, other.x);
+        let right_x  = max!(self.x + self.width, other.x + other.width);
+        let top_y    = min!(self.y, other.y);
+        let bottom_y = max!(self.y + self.height, other.y + other.height);
+
+        Self {
+            x: left_x,
+            y: top_y,
+            width: right_x - left_x,
+            height: bottom_y - top_y,
+        }
+    }
+
     /// Shrinks the area from each side by `count`.
     ///
+    /// For a variant that does not underflow, see [`Area::inset_xy`] and
+    /// [`Area::inset_sides`].
+    ///
     /// # Underflows
     ///
     /// When `width` or `height` are less than `count * 2`.
@@ -682,8 +1004,59 @@ impl Area {
         }
     }
 
+    /// Shrinks the area by `horiz` from the left and the right and by `vert`
+    /// from the top and the bottom.
+    ///
+    /// Saturates, see [`Area::inset_sides`].
+    #[inline]
+    pub const fn inset_xy(&self, horiz: u16, vert: u16) -> Self
+    {
+        self.inset_sides(vert, horiz, vert, horiz)
+    }
+
+    /// Shrinks the area from each side by the respective amount.
+    ///
+    /// When the insets exceed the dimensions, the area is collapsed (its width
+    /// or height is `0`) instead of underflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let area = Area { x: 2, y: 2, width: 10, height: 4 };
+    ///
+    /// assert_eq!(
+    ///     area.inset_sides(1, 2, 0, 3),
+    ///     Area { x: 5, y: 3, width: 5, height: 3 }
+    /// );
+    /// assert_eq!(
+    ///     area.inset_xy(1, 3),
+    ///     Area { x: 3, y: 5, width: 8, height: 0 }
+    /// );
+    /// ```
+    #[inline]
+    pub const fn inset_sides(
+        &self,
+        top: u16,
+        right: u16,
+        bottom: u16,
+        left: u16
+    ) -> Self
+    {
+        Self {
+            x: self.x + min!(left, self.width),
+            y: self.y + min!(top, self.height),
+            width: self.width.saturating_sub(left.saturating_add(right)),
+            height: self.height.saturating_sub(top.saturating_add(bottom)),
+        }
+    }
+
     /// Splits the area horizontally at `y` relative to the start of the area.
     ///
+    /// For variants that do not panic, see [`Area::try_split_horiz_at`] and
+    /// [`Area::split_horiz_at_saturating`].
+    ///
     /// # Panics
     ///
     /// When `y` is greater than the height.
@@ -711,6 +1084,9 @@ impl Area {
 
     /// Splits the area vertically at `x` relative to the start of the area.
     ///
+    /// For variants that do not panic, see [`Area::try_split_vert_at`] and
+    /// [`Area::split_vert_at_saturating`].
+    ///
     /// # Panics
     ///
     /// When `x` is greater than the width.
@@ -736,6 +1112,189 @@ impl Area {
         )
     }
 
+    /// Splits the area horizontally at `y` relative to the start of the area,
+    /// if `y` is not greater than the height.
+    ///
+    /// Never panics, see [`Area::split_horiz_at`].
+    #[inline]
+    pub const fn try_split_horiz_at(&self, y: u16) -> Option<(Self, Self)>
+    {
+        if y > self.height {
+            return None;
+        }
+
+        Some(self.split_horiz_at(y))
+    }
+
+    /// Splits the area vertically at `x` relative to the start of the area,
+    /// if `x` is not greater than the width.
+    ///
+    /// Never panics, see [`Area::split_vert_at`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let area = Area { x: 1, y: 1, width: 10, height: 4 };
+    ///
+    /// assert_eq!(
+    ///     area.try_split_vert_at(3),
+    ///     Some((
+    ///         Area { x: 1, y: 1, width: 3, height: 4 },
+    ///         Area { x: 4, y: 1, width: 7, height: 4 },
+    ///     ))
+    /// );
+    /// assert_eq!(area.try_split_vert_at(11), None);
+    /// assert_eq!(area.try_split_horiz_at(5), None);
+    /// ```
+    #[inline]
+    pub const fn try_split_vert_at(&self, x: u16) -> Option<(Self, Self)>
+    {
+        if x > self.width {
+            return None;
+        }
+
+        Some(self.split_vert_at(x))
+    }
+
+    /// Splits the area horizontally at `y` relative to the start of the area,
+    /// with `y` clamped to the height.
+    ///
+    /// Never panics. When `y` is past the end, the second area is collapsed.
+    #[inline]
+    pub const fn split_horiz_at_saturating(&self, y: u16) -> (Self, Self)
+    {
+        self.split_horiz_at(min!(y, self.height))
+    }
+
+    /// Splits the area vertically at `x` relative to the start of the area,
+    /// with `x` clamped to the width.
+    ///
+    /// Never panics. When `x` is past the end, the second area is collapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let area = Area { x: 1, y: 1, width: 10, height: 4 };
+    ///
+    /// assert_eq!(
+    ///     area.split_vert_at_saturating(20),
+    ///     (area, Area { x: 11, y: 1, width: 0, height: 4 })
+    /// );
+    /// assert_eq!(
+    ///     area.split_horiz_at_saturating(20),
+    ///     (area, Area { x: 1, y: 5, width: 10, height: 0 })
+    /// );
+    /// ```
+    #[inline]
+    pub const fn split_vert_at_saturating(&self, x: u16) -> (Self, Self)
+    {
+        self.split_vert_at(min!(x, self.width))
+    }
+
+    /// Splits the area into `n` rows of equal height.
+    ///
+    /// When the height is not divisible by `n`, the last row takes the
+    /// remainder as well, like [`Col`](crate::widget::Col) does. When `n` is
+    /// greater than the height, all the rows but the last one are collapsed.
+    /// When `n` is `0`, there are no rows.
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let area = Area { x: 2, y: 1, width: 4, height: 8 };
+    /// let rows: Vec<Area> = area.split_rows(3).collect();
+    ///
+    /// assert_eq!(rows, [
+    ///     Area { x: 2, y: 1, width: 4, height: 2 },
+    ///     Area { x: 2, y: 3, width: 4, height: 2 },
+    ///     Area { x: 2, y: 5, width: 4, height: 4 },
+    /// ]);
+    /// assert_eq!(area.split_rows(0).count(), 0);
+    /// assert_eq!(area.split_rows(10).filter(|a| a.is_collapsed()).count(), 9);
+    /// ```
+    #[inline]
+    pub const fn split_rows(&self, n: u16) -> Splits
+    {
+        Splits {
+            rest: *self,
+            size: match self.height.checked_div(n) {
+                Some(size) => size,
+                None => 0,
+            },
+            remaining: n,
+            horiz: true,
+        }
+    }
+
+    /// Splits the area into `n` columns of equal width.
+    ///
+    /// When the width is not divisible by `n`, the last column takes the
+    /// remainder as well, like [`Row`](crate::widget::Row) does. When `n` is
+    /// greater than the width, all the columns but the last one are
+    /// collapsed. When `n` is `0`, there are no columns.
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let area = Area { x: 0, y: 0, width: 10, height: 2 };
+    /// let widths: Vec<u16> = area.split_columns(4).map(|a| a.width).collect();
+    ///
+    /// assert_eq!(widths, [2, 2, 2, 4]);
+    /// ```
+    #[inline]
+    pub const fn split_columns(&self, n: u16) -> Splits
+    {
+        Splits {
+            rest: *self,
+            size: match self.width.checked_div(n) {
+                Some(size) => size,
+                None => 0,
+            },
+            remaining: n,
+            horiz: false,
+        }
+    }
+
+    /// Splits the area horizontally at `pct` percent of the height.
+    ///
+    /// The height of the first area is rounded half-up and `pct` is clamped to
+    /// `100`.
+    #[inline]
+    pub const fn split_horiz_pct(&self, pct: u8) -> (Self, Self)
+    {
+        self.split_horiz_at(percent(self.height, pct))
+    }
+
+    /// Splits the area vertically at `pct` percent of the width.
+    ///
+    /// The width of the first area is rounded half-up and `pct` is clamped to
+    /// `100`.
+    ///
+    /// ```
+    /// use cwinui::layout::Area;
+    ///
+    /// let area = Area { x: 0, y: 0, width: 10, height: 5 };
+    ///
+    /// let (left, right) = area.split_vert_pct(30);
+    /// assert_eq!((left.width, right.width), (3, 7));
+    ///
+    /// // 2.5 and 7.5 round up.
+    /// assert_eq!(area.split_horiz_pct(50).0.height, 3);
+    /// assert_eq!(area.split_vert_pct(75).0.width, 8);
+    ///
+    /// assert_eq!(area.split_vert_pct(0).0.width, 0);
+    /// assert_eq!(area.split_vert_pct(200).0, area);
+    /// ```
+    #[inline]
+    pub const fn split_vert_pct(&self, pct: u8) -> (Self, Self)
+    {
+        self.split_vert_at(percent(self.width, pct))
+    }
+
     /// Splits the area horizontally at `y`.
     ///
     /// # Panics
@@ -901,6 +1460,125 @@ impl Area {
     }
 }
 
+/// Iterator over the positions of the cells in an [`Area`], see
+/// [`Area::positions`].
+#[derive(Debug, Clone)]
+pub struct Positions {
+    area: Area,
+    next: Pos,
+}
+
+impl Iterator for Positions {
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let end = self.area.bottom_right();
+        if self.area.is_collapsed() || self.next.y >= end.y {
+            return None;
+        }
+
+        let pos = self.next;
+
+        self.next.x += 1;
+        if self.next.x >= end.x {
+            self.next.x = self.area.x;
+            self.next.y += 1;
+        }
+
+        Some(pos)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Positions {
+    #[inline]
+    fn len(&self) -> usize
+    {
+        let end = self.area.bottom_right();
+        if self.area.is_collapsed() || self.next.y >= end.y {
+            return 0;
+        }
+
+        let rows_after = (end.y - self.next.y - 1) as usize;
+
+        rows_after * self.area.width as usize + (end.x - self.next.x) as usize
+    }
+}
+
+impl std::iter::FusedIterator for Positions {}
+
+/// Computes `pct` percent of `size`, rounded half-up, with `pct` clamped to
+/// `100`.
+#[inline]
+const fn percent(size: u16, pct: u8) -> u16
+{
+    let pct = min!(pct, 100) as u32;
+
+    ((size as u32 * pct + 50) / 100) as u16
+}
+
+/// Iterator over equally-sized parts of an [`Area`], see [`Area::split_rows`]
+/// and [`Area::split_columns`].
+#[derive(Debug, Clone)]
+pub struct Splits {
+    rest: Area,
+    size: u16,
+    remaining: u16,
+    horiz: bool,
+}
+
+impl Iterator for Splits {
+    type Item = Area;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        match self.remaining {
+            0 => None,
+            1 => {
+                self.remaining = 0;
+
+                Some(self.rest)
+            },
+            _ => {
+                self.remaining -= 1;
+
+                let (area, rest) = if self.horiz
+                    { self.rest.split_horiz_at(self.size) }
+                    else { self.rest.split_vert_at(self.size) };
+                self.rest = rest;
+
+                Some(area)
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Splits {
+    #[inline]
+    fn len(&self) -> usize
+    {
+        self.remaining as usize
+    }
+}
+
+impl std::iter::FusedIterator for Splits {}
+
 /// Alignment of an item within a rectangle.
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub enum Alignment {
@@ -5,6 +5,7 @@ pub mod alloc;
 pub mod render;
 pub mod backend;
 pub mod buffer;
+pub mod boxdraw;
 
 mod util;
 
@@ -1,5 +1,7 @@
 use crate::layout::{Area, Pos, Dim, Justify};
-use crate::style::{AsStyledStr, StyledChar};
+use crate::style::{AsStyledStr, Style, StyledChar, StyledStr};
+use crate::util::{fit_width, str_width};
+use crate::widget::border::Theme;
 
 /// Render - the basic mechanism for drawing widgets.
 ///
@@ -10,6 +12,10 @@ pub trait Render {
 
     /// Set a `StyledStr`.
     ///
+    /// Tabs are expanded to spaces (see [`Render::tab_width`]). Other control
+    /// characters (including newlines) are written as spaces. For printing
+    /// text with newlines, see [`Render::print_multiline`].
+    ///
     /// # Panics
     ///
     /// When out of bounds.
@@ -24,9 +30,85 @@ pub trait Render {
     where
         C: Into<StyledChar>;
 
+    /// Merge `style` into the cell at `pos`, leaving its character intact.
+    ///
+    /// The default implementation reads the cell back with [`Render::get`],
+    /// so it has no effect on renderers that do not support reading back
+    /// cells.
+    ///
+    /// # Panics
+    ///
+    /// When out of bounds.
+    #[inline]
+    fn set_style(&mut self, pos: Pos, style: Style)
+    {
+        let area = self.area();
+        assert!(pos.x < area.width && pos.y < area.height);
+
+        if let Some(c) = self.get(pos) {
+            self.set_char(pos, StyledChar { content: c.content, style });
+        }
+    }
+
     /// Clear the buffer.
     fn clear(&mut self);
 
+    /// Get the cell that the buffer is cleared with.
+    #[inline]
+    fn blank(&self) -> StyledChar
+    {
+        StyledChar { content: ' ', style: Style::default() }
+    }
+
+    /// Get the width of tab stops used for expanding `'\t'` in strings.
+    ///
+    /// Tab stops are relative to the start of the string.
+    #[inline]
+    fn tab_width(&self) -> u16
+    {
+        8
+    }
+
+    /// Get the cell at `pos` in the current frame.
+    ///
+    /// Returns `None` when the renderer does not support reading back cells
+    /// (the default), or when `pos` is out of bounds.
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::{Area, Pos};
+    ///
+    /// let mut backend = MemBackend::new(4, 2);
+    ///
+    /// backend.render(|buf| {
+    ///     buf.set_char(Pos { x: 2, y: 1 }, 'a');
+    ///
+    ///     assert_eq!(buf.get(Pos { x: 2, y: 1 }).map(|c| c.content), Some('a'));
+    ///     assert_eq!(buf.get(Pos { x: 4, y: 1 }), None);
+    ///
+    ///     let clipped = buf.clipped(Area { x: 2, y: 1, width: 2, height: 1 });
+    ///     assert_eq!(clipped.get(Pos::ZERO).map(|c| c.content), Some('a'));
+    ///     assert_eq!(clipped.get(Pos { x: 0, y: 1 }), None);
+    /// });
+    /// ```
+    #[inline]
+    fn get(&self, _pos: Pos) -> Option<StyledChar>
+    {
+        None
+    }
+
+    /// Get the cell at `pos` in the previously presented frame.
+    ///
+    /// Returns `None` when the renderer does not retain the previous frame
+    /// (the default), or when `pos` is out of bounds. Retaining the previous
+    /// frame is opt-in for the backends that support it.
+    #[inline]
+    fn prev_char(&self, _pos: Pos) -> Option<StyledChar>
+    {
+        None
+    }
+
     /// Show the cursor.
     fn show_cursor(&mut self);
 
@@ -36,6 +118,13 @@ pub trait Render {
     /// Move the cursor.
     fn move_cursor(&mut self, pos: Pos);
 
+    /// Set the shape of the cursor.
+    ///
+    /// The shape only takes effect while the cursor is shown. Renderers that
+    /// do not support cursor shapes ignore it (the default).
+    #[inline]
+    fn set_cursor_shape(&mut self, _shape: CursorShape) {}
+
     // Helper methods.
 
     /// Get the dimensions of the paint area.
@@ -61,12 +150,8 @@ pub trait Render {
 
         let c = c.into();
 
-        for y in 0..area.height {
-            for x in 0..area.width {
-                let x = area.x + x;
-                let y = area.y + y;
-                self.set_char(Pos { x, y }, c);
-            }
+        for pos in area.positions() {
+            self.set_char(pos, c);
         }
     }
 
@@ -91,6 +176,24 @@ pub trait Render {
     }
 
     /// Fill a vertical line with `c`,  of length `len` and starting a `pos`.
+    ///
+    /// The line is cut off at the bottom edge of the paint area.
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::Pos;
+    ///
+    /// let mut backend = MemBackend::new(6, 2);
+    ///
+    /// backend.render(|buf| {
+    ///     buf.vfill(Pos { x: 4, y: 1 }, '|', 5);
+    ///     buf.clipped(buf.area()).vfill(Pos { x: 1, y: 0 }, '|', 5);
+    /// });
+    /// backend.flush().unwrap();
+    ///
+    /// assert_eq!(backend.to_string(), " |    \n |  | ");
+    /// ```
     #[inline]
     fn vfill<T>(&mut self, pos: Pos, c: T, len: usize)
     where
@@ -111,6 +214,28 @@ pub trait Render {
     }
 
     /// Bounds-checked absolute printing.
+    ///
+    /// The text is truncated to the display columns available in the paint
+    /// area. Text outside of the paint area is not printed.
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::Pos;
+    ///
+    /// let mut backend = MemBackend::new(20, 2);
+    ///
+    /// backend.render(|buf| {
+    ///     buf.print_abs(Pos { x: 17, y: 1 }, "abcde");
+    ///     buf.print_abs(Pos { x: 0, y: 3 }, "lost");
+    ///     buf.print_abs(Pos { x: 20, y: 0 }, "lost");
+    /// });
+    /// backend.flush().unwrap();
+    ///
+    /// let output = backend.to_string();
+    /// assert_eq!(output.lines().nth(1), Some("                 abc"));
+    /// assert!(!output.contains("lost"));
+    /// ```
     #[inline]
     fn print_abs<S: AsStyledStr>(&mut self, pos: Pos, text: S)
     {
@@ -121,14 +246,13 @@ pub trait Render {
         }
 
         let text = text.as_styled_str();
-
-        // TODO: utf8 support.
-        let print_width = std::cmp::min(
-            text.content.len(),
-            area.width as usize - pos.x as usize
+        let print_len = fit_width(
+            text.content,
+            area.width as usize - pos.x as usize,
+            self.tab_width() as usize,
         );
 
-        self.set_str(pos, text.slice(..print_width));
+        self.set_str(pos, text.slice(..print_len));
     }
 
     /// Bounds-checked absolute printing of a styled character.
@@ -146,7 +270,42 @@ pub trait Render {
         self.set_char(pos, c);
     }
 
+    /// Bounds-checked version of [`Render::set_style`].
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::Pos;
+    /// use cwinui::style::{Color, Style};
+    ///
+    /// let mut backend = MemBackend::new(3, 1);
+    ///
+    /// backend.render(|buf| {
+    ///     let pos = Pos { x: 1, y: 0 };
+    ///     buf.set_char(pos, 'a');
+    ///     buf.restyle(pos, Style::default().bg(Color::Blue));
+    ///     buf.restyle(Pos { x: 3, y: 0 }, Style::default());
+    ///
+    ///     let cell = buf.get(pos).unwrap();
+    ///     assert_eq!(cell.content, 'a');
+    ///     assert_eq!(cell.style.bg_color, Some(Color::Blue));
+    /// });
+    /// ```
+    #[inline]
+    fn restyle(&mut self, pos: Pos, style: Style)
+    {
+        let area = self.area();
+
+        if pos.x >= area.width || pos.y >= area.height {
+            return;
+        }
+
+        self.set_style(pos, style);
+    }
+
     /// Bounds-checked print, relative to `area`.
+    ///
+    /// The text is truncated to the display columns available in `area`.
     #[inline]
     fn print<S: AsStyledStr>(&mut self, pos: Pos, text: S, area: Area)
     {
@@ -164,14 +323,34 @@ pub trait Render {
 
         let text = text.as_styled_str();
         let right_max  = area.x as usize + area.width as usize;
-
-        // TODO: utf8 support.
-        let print_width = std::cmp::min(
-            text.content.len(),
-            right_max - abs_x as usize
+        let print_len = fit_width(
+            text.content,
+            right_max - abs_x as usize,
+            self.tab_width() as usize,
         );
 
-        self.set_str(Pos{x:abs_x,y:abs_y}, text.slice(..print_width));
+        self.set_str(Pos{x:abs_x,y:abs_y}, text.slice(..print_len));
+    }
+
+    /// Bounds-checked print of multi-line text, relative to `area`.
+    ///
+    /// `text` is split on `'\n'` and each line is printed on a successive row,
+    /// starting at `pos`. Lines that fall outside of `area` are clipped.
+    #[inline]
+    fn print_multiline<S: AsStyledStr>(&mut self, pos: Pos, text: S, area: Area)
+    {
+        let text = text.as_styled_str();
+
+        for (i, line) in text.content.split('\n').enumerate() {
+            let y = pos.y as usize + i;
+
+            if y >= area.height as usize {
+                break;
+            }
+
+            let line = StyledStr { content: line, style: text.style };
+            self.print(Pos { x: pos.x, y: y as u16 }, line, area);
+        }
     }
 
     /// Bounds-checked print of a styled character, relative to `area`.
@@ -192,7 +371,53 @@ pub trait Render {
         self.set_char(pos + area.top_left(), c);
     }
 
+    /// Draw the outline of `area` with the characters of `theme`.
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::Render;
+    /// use cwinui::layout::Area;
+    /// use cwinui::widget::border::Theme;
+    ///
+    /// let mut backend = MemBackend::new(5, 4);
+    ///
+    /// backend.render(|buf| {
+    ///     buf.rect(Area { x: 1, y: 1, width: 4, height: 3 }, &Theme::ascii());
+    /// });
+    /// backend.flush().unwrap();
+    ///
+    /// assert_eq!(backend.to_string(), "     \n +--+\n |  |\n +--+");
+    /// ```
+    #[inline]
+    fn rect(&mut self, area: Area, theme: &Theme)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        // Sides
+
+        let top_left = area.top_left();
+        let top_right = area.top_right().sub_x(1);
+        let bottom_left = area.bottom_left().sub_y(1);
+        let bottom_right = area.bottom_right() - Pos { x: 1, y: 1 };
+
+        self.hfill(top_left, theme.top, area.width as usize);
+        self.hfill(bottom_left, theme.bottom, area.width as usize);
+        self.vfill(top_left, theme.left, area.height as usize);
+        self.vfill(top_right, theme.right, area.height as usize);
+
+        // Corners
+
+        self.putc_abs(top_left, theme.top_left);
+        self.putc_abs(top_right, theme.top_right);
+        self.putc_abs(bottom_left, theme.bottom_left);
+        self.putc_abs(bottom_right, theme.bottom_right);
+    }
+
     /// Print justified in an area.
+    ///
+    /// The justification is based on the display width of the text.
     #[inline]
     fn jprint<S: AsStyledStr>(&mut self, text: S, j: Justify, area: Area)
     {
@@ -206,8 +431,12 @@ pub trait Render {
         }
 
         let text = text.as_styled_str();
-        // TODO: utf8 support.
-        let text_width = std::cmp::min(text.content.len(), area.width as usize);
+        let text_width = std::cmp::min(
+            str_width(text.content),
+            area.width as usize
+        ) as u16;
+        // Over-wide text is left-anchored and truncated by `print`.
+        let free_width = area.width - text_width;
 
         let pos = match j {
             Justify::Left(y) => Pos {
@@ -215,11 +444,11 @@ pub trait Render {
                 y
             },
             Justify::HCenter(y) => Pos {
-                x: (area.width as usize - text_width) as u16 / 2,
+                x: free_width / 2,
                 y,
             },
             Justify::Right(y) => Pos {
-                x: (area.width as usize - text_width) as u16,
+                x: free_width,
                 y,
             },
             Justify::Top(x) => Pos {
@@ -236,11 +465,11 @@ pub trait Render {
             },
             Justify::TopLeft => Pos::ZERO,
             Justify::TopCenter => Pos {
-                x: (area.width as usize - text_width) as u16 / 2,
+                x: free_width / 2,
                 y: 0,
             },
             Justify::TopRight => Pos {
-                x: (area.width as usize - text_width) as u16,
+                x: free_width,
                 y: 0,
             },
             Justify::CenterLeft => Pos {
@@ -248,11 +477,11 @@ pub trait Render {
                 y: area.height.saturating_sub(1) / 2,
             },
             Justify::Center => Pos {
-                x: (area.width as usize - text_width) as u16 / 2,
+                x: free_width / 2,
                 y: area.height.saturating_sub(1) / 2,
             },
             Justify::CenterRight => Pos {
-                x: (area.width as usize - text_width) as u16,
+                x: free_width,
                 y: area.height.saturating_sub(1) / 2,
             },
             Justify::BottomLeft => Pos {
@@ -260,16 +489,16 @@ pub trait Render {
                 y: area.height.saturating_sub(1),
             },
             Justify::BottomCenter => Pos {
-                x: (area.width as usize - text_width) as u16 / 2,
+                x: free_width / 2,
                 y: area.height.saturating_sub(1),
             },
             Justify::BottomRight => Pos {
-                x: (area.width as usize - text_width) as u16,
+                x: free_width,
                 y: area.height.saturating_sub(1),
             },
         };
 
-        self.print(pos, text.slice(..text_width), area);
+        self.print(pos, text, area);
     }
 
     /// Putc justified in an area.
@@ -351,10 +580,308 @@ pub trait Render {
 
         self.putc(pos, c, area);
     }
+
+    /// Get a view of the paint area clipped to `area`.
+    ///
+    /// See [`ClippedRender`].
+    #[inline]
+    fn clipped(&mut self, area: Area) -> ClippedRender<'_, Self>
+    where
+        Self: Sized
+    {
+        ClippedRender::new(self, area)
+    }
+}
+
+
+/// View of a [`Render`]er, clipped to a sub-area.
+///
+/// The coordinates are translated, so that `(0, 0)` is the top left corner of
+/// the clip area. Writes outside of the clip area are silently dropped, which
+/// gives containers a hard clip boundary.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::widget::Canvas;
+/// use cwinui::render::Render;
+/// use cwinui::layout::{Area, Dim, Pos};
+///
+/// let mut canvas = Canvas::new(Dim { width: 10, height: 5 });
+/// let mut renderer = canvas.renderer();
+///
+/// let clip = Area { x: 8, y: 1, width: 4, height: 2 };
+/// let mut clipped = renderer.clipped(clip);
+///
+/// // The clip area is intersected with the paint area.
+/// assert_eq!(clipped.area(), Area { x: 0, y: 0, width: 2, height: 2 });
+///
+/// // Writes outside of the clip area are dropped.
+/// clipped.set_str(Pos { x: 0, y: 0 }, "overflowing");
+/// clipped.set_char(Pos { x: 5, y: 5 }, 'x');
+/// ```
+#[derive(Debug)]
+pub struct ClippedRender<'a, R: Render> {
+    inner: &'a mut R,
+    clip: Area,
+}
+
+impl<'a, R: Render> ClippedRender<'a, R> {
+    /// Creates a view of `inner` clipped to `area`.
+    ///
+    /// `area` is intersected with the paint area of `inner`.
+    pub fn new(inner: &'a mut R, area: Area) -> Self
+    {
+        let outer = inner.area();
+        let clip = if outer.overlaps(area)
+            { outer.intersection(area) }
+            else { Area::from_parts(outer.top_left(), Dim::default()) };
+
+        Self {
+            inner,
+            clip,
+        }
+    }
+
+    /// Translates `pos` to the coordinates of the inner renderer.
+    #[inline]
+    fn translate(&self, pos: Pos) -> Pos
+    {
+        self.clip.top_left() + pos
+    }
+
+    #[inline]
+    fn contains(&self, pos: Pos) -> bool
+    {
+        pos.x < self.clip.width && pos.y < self.clip.height
+    }
+}
+
+impl<R: Render> Render for ClippedRender<'_, R> {
+    #[inline]
+    fn area(&self) -> Area
+    {
+        Area::from_parts(Pos::ZERO, self.clip.dimensions())
+    }
+
+    fn set_str<S: AsStyledStr>(&mut self, pos: Pos, text: S)
+    {
+        if !self.contains(pos) {
+            return;
+        }
+
+        let text = text.as_styled_str();
+        let avail = (self.clip.width - pos.x) as usize;
+        let end = fit_width(text.content, avail, self.tab_width() as usize);
+
+        self.inner.set_str(self.translate(pos), text.slice(..end));
+    }
+
+    #[inline]
+    fn set_char<C>(&mut self, pos: Pos, c: C)
+    where
+        C: Into<StyledChar>
+    {
+        if !self.contains(pos) {
+            return;
+        }
+
+        self.inner.set_char(self.translate(pos), c);
+    }
+
+    #[inline]
+    fn set_style(&mut self, pos: Pos, style: Style)
+    {
+        if !self.contains(pos) {
+            return;
+        }
+
+        self.inner.set_style(self.translate(pos), style);
+    }
+
+    /// Clears the clip area only, with the blank cell of the inner renderer.
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::layout::{Area, Pos};
+    /// use cwinui::render::Render;
+    /// use cwinui::style::Style;
+    ///
+    /// let mut backend = MemBackend::new(4, 1);
+    /// backend.set_clear('·', Style::default());
+    ///
+    /// backend.render(|buf| {
+    ///     buf.set_str(Pos::ZERO, "abcd");
+    ///     buf.clipped(Area { x: 1, y: 0, width: 2, height: 1 }).clear();
+    /// });
+    /// backend.flush().unwrap();
+    ///
+    /// assert_eq!(backend.to_string(), "a··d");
+    /// ```
+    fn clear(&mut self)
+    {
+        let blank = self.inner.blank();
+        // The blank style replaces the style of the cells instead of being
+        // merged into it.
+        let blank = StyledChar {
+            content: blank.content,
+            style: Style::default().clean().merge(blank.style),
+        };
+        self.inner.fill(blank, self.clip);
+    }
+
+    #[inline]
+    fn blank(&self) -> StyledChar
+    {
+        self.inner.blank()
+    }
+
+    #[inline]
+    fn tab_width(&self) -> u16
+    {
+        self.inner.tab_width()
+    }
+
+    #[inline]
+    fn get(&self, pos: Pos) -> Option<StyledChar>
+    {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        self.inner.get(self.translate(pos))
+    }
+
+    #[inline]
+    fn prev_char(&self, pos: Pos) -> Option<StyledChar>
+    {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        self.inner.prev_char(self.translate(pos))
+    }
+
+    #[inline]
+    fn show_cursor(&mut self)
+    {
+        self.inner.show_cursor();
+    }
+
+    #[inline]
+    fn hide_cursor(&mut self)
+    {
+        self.inner.hide_cursor();
+    }
+
+    #[inline]
+    fn move_cursor(&mut self, pos: Pos)
+    {
+        if !self.contains(pos) {
+            return;
+        }
+
+        self.inner.move_cursor(self.translate(pos));
+    }
+
+    #[inline]
+    fn set_cursor_shape(&mut self, shape: CursorShape)
+    {
+        self.inner.set_cursor_shape(shape);
+    }
+}
+
+
+/// Shape of the terminal cursor, see [`Render::set_cursor_shape`].
+///
+/// The shapes correspond to the parameters of the DECSCUSR escape sequence.
+/// The default, [`CursorShape::TerminalDefault`], keeps the shape configured
+/// in the terminal.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, WriterBackend};
+/// use cwinui::render::{CursorShape, Render};
+/// use cwinui::layout::Pos;
+///
+/// let mut backend = WriterBackend::new(Vec::new(), 4, 1);
+///
+/// backend.render(|buf| {
+///     buf.set_cursor_shape(CursorShape::SteadyBar);
+///     buf.move_cursor(Pos { x: 1, y: 0 });
+///     buf.show_cursor();
+/// });
+/// backend.flush().unwrap();
+///
+/// let output = String::from_utf8_lossy(backend.writer());
+/// assert!(output.contains("\x1b[6 q"));
+///
+/// // Without setting a shape, the one configured in the terminal is used.
+/// let mut backend = WriterBackend::new(Vec::new(), 4, 1);
+/// backend.render(|buf| buf.show_cursor());
+/// backend.flush().unwrap();
+///
+/// let output = String::from_utf8_lossy(backend.writer());
+/// assert!(output.contains("\x1b[0 q"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, std::hash::Hash)]
+pub enum CursorShape {
+    /// The shape configured in the terminal.
+    #[default]
+    TerminalDefault,
+    BlinkingBlock,
+    SteadyBlock,
+    BlinkingUnderline,
+    SteadyUnderline,
+    BlinkingBar,
+    SteadyBar,
+}
+
+impl CursorShape {
+    /// Gets the parameter of the DECSCUSR (`"\x1b[N q"`) escape sequence.
+    #[inline]
+    pub const fn decscusr(self) -> u8
+    {
+        match self {
+            Self::TerminalDefault   => 0,
+            Self::BlinkingBlock     => 1,
+            Self::SteadyBlock       => 2,
+            Self::BlinkingUnderline => 3,
+            Self::SteadyUnderline   => 4,
+            Self::BlinkingBar       => 5,
+            Self::SteadyBar         => 6,
+        }
+    }
 }
 
 
 /// The type can be drawn with a [`Render`]er.
+///
+/// Tuples of up to 8 widgets are drawn in the same area, in order, like a
+/// static [`Stack`](crate::widget::Stack): the first element ends up at the
+/// bottom and the last one on top. Tuples do not implement
+/// [`Proportional`](crate::layout::Proportional), as there is no single way to
+/// combine the proportions of the elements. The same goes for slices of
+/// `&dyn Draw`, for a dynamic number of widgets.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::style::StyledChar;
+/// use cwinui::widget::{Filler, Wireframe};
+///
+/// let bg = Filler(StyledChar::from('.'));
+/// let fg = Wireframe::new().size(false).diagonals(false);
+///
+/// let mut backend = MemBackend::new(5, 3);
+/// backend.render(|buf| (&bg, &fg).draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "+-v-+\n>.x.<\n+-^-+");
+/// ```
 pub trait Draw<R: Render> {
     /// Draws the widget onto `buf`.
     fn draw(&self, buf: &mut R, area: Area);
@@ -379,3 +906,99 @@ where
         T::draw(*self, buf, area);
     }
 }
+
+/// `Some` draws the inner widget, `None` draws nothing.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::layout::{Proportional, Proportions};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::text::Line;
+///
+/// let mut backend = MemBackend::new(6, 1);
+/// let mut status = Some(Line("ready"));
+///
+/// backend.render(|buf| status.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "ready ");
+///
+/// status = None;
+/// backend.render(|buf| status.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "      ");
+/// assert_eq!(status.proportions(), Proportions::ZERO);
+/// ```
+impl<T, R: Render> Draw<R> for Option<T>
+where
+    T: Draw<R>,
+{
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if let Some(inner) = self {
+            inner.draw(buf, area);
+        }
+    }
+}
+
+/// The widgets are drawn in the same area, in order: the first element ends
+/// up at the bottom and the last one on top.
+///
+/// This is the dynamic counterpart of the implementation for tuples, see
+/// [`Draw`].
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::style::StyledChar;
+/// use cwinui::widget::Filler;
+/// use cwinui::widget::text::Line;
+///
+/// let bg = Filler(StyledChar::from('.'));
+/// let text = Line("hi");
+///
+/// let mut backend = MemBackend::new(4, 1);
+/// backend.render(|buf| {
+///     let layers: &[&dyn Draw<_>] = &[&bg, &text];
+///     layers.draw(buf, buf.area());
+/// });
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "hi..");
+/// ```
+impl<R: Render> Draw<R> for [&dyn Draw<R>] {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        for it in self {
+            it.draw(buf, area);
+        }
+    }
+}
+
+// See the documentation of `Draw` for tuples.
+macro_rules! impl_draw_for_tuple {
+    ($($t:ident),+) => {
+        impl<$($t,)+ R: Render> Draw<R> for ($($t,)+)
+        where
+            $($t: Draw<R>,)+
+        {
+            #[allow(non_snake_case)]
+            fn draw(&self, buf: &mut R, area: Area)
+            {
+                let ($($t,)+) = self;
+                $($t.draw(buf, area);)+
+            }
+        }
+    };
+}
+
+impl_draw_for_tuple!(A, B);
+impl_draw_for_tuple!(A, B, C);
+impl_draw_for_tuple!(A, B, C, D);
+impl_draw_for_tuple!(A, B, C, D, E);
+impl_draw_for_tuple!(A, B, C, D, E, F);
+impl_draw_for_tuple!(A, B, C, D, E, F, G);
+impl_draw_for_tuple!(A, B, C, D, E, F, G, H);
@@ -1,5 +1,7 @@
 use bitflags::bitflags;
 
+use crate::util::char_width;
+
 /// Styling data used to style text.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, std::hash::Hash)]
 pub struct Style {
@@ -86,17 +88,115 @@ impl Style {
     }
 }
 
+/// Colors for the common roles in an application.
+///
+/// A pure convenience over [`Style`], so that the same colors can be shared
+/// between widgets.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::style::{Color, Palette, Style};
+///
+/// let palette = Palette::default_dark();
+///
+/// assert_eq!(
+///     palette.accent_style(),
+///     Style::default().fg(Color::LightCyan).bg(Color::Black)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, std::hash::Hash)]
+pub struct Palette {
+    pub fg: Color,
+    pub bg: Color,
+    pub accent: Color,
+    pub muted: Color,
+    pub error: Color,
+    pub border: Color,
+}
+
+impl Palette {
+    /// Light text on a dark background.
+    pub const fn default_dark() -> Self
+    {
+        Self {
+            fg: Color::White,
+            bg: Color::Black,
+            accent: Color::LightCyan,
+            muted: Color::LightBlack,
+            error: Color::LightRed,
+            border: Color::White,
+        }
+    }
+
+    /// Dark text on a light background.
+    pub const fn default_light() -> Self
+    {
+        Self {
+            fg: Color::Black,
+            bg: Color::LightWhite,
+            accent: Color::Blue,
+            muted: Color::LightBlack,
+            error: Color::Red,
+            border: Color::LightBlack,
+        }
+    }
+
+    /// Style of regular text: `fg` on `bg`.
+    #[inline]
+    pub const fn base_style(&self) -> Style
+    {
+        self.role_style(self.fg)
+    }
+
+    /// Style of highlighted text: `accent` on `bg`.
+    #[inline]
+    pub const fn accent_style(&self) -> Style
+    {
+        self.role_style(self.accent)
+    }
+
+    /// Style of less important text: `muted` on `bg`.
+    #[inline]
+    pub const fn muted_style(&self) -> Style
+    {
+        self.role_style(self.muted)
+    }
+
+    /// Style of error messages: `error` on `bg`.
+    #[inline]
+    pub const fn error_style(&self) -> Style
+    {
+        self.role_style(self.error)
+    }
+
+    /// Style of borders and separators: `border` on `bg`.
+    #[inline]
+    pub const fn border_style(&self) -> Style
+    {
+        self.role_style(self.border)
+    }
+
+    #[inline]
+    const fn role_style(&self, fg: Color) -> Style
+    {
+        Style::default().fg(fg).bg(self.bg)
+    }
+}
+
 bitflags! {
     /// Used to define style special text styling in consoles, e.g. bold text,
     /// underlined text, blinking, etc.
     #[derive(Default)]
     pub struct TextStyle: u8 {
-        const NORMAL    = 0b00000000;
-        const BOLD      = 0b00000001;
-        const BLINK     = 0b00000010;
-        const INVERT    = 0b00000100;
-        const ITALIC    = 0b00001000;
-        const UNDERLINE = 0b00010000;
+        const NORMAL        = 0b00000000;
+        const BOLD          = 0b00000001;
+        const BLINK         = 0b00000010;
+        const INVERT        = 0b00000100;
+        const ITALIC        = 0b00001000;
+        const UNDERLINE     = 0b00010000;
+        const STRIKETHROUGH = 0b00100000;
+        const DIM           = 0b01000000;
     }
 }
 
@@ -126,6 +226,237 @@ pub enum Color {
     Rgb(u8, u8, u8),
 }
 
+impl Color {
+    /// Converts the color to the nearest one available with `level` of color
+    /// support.
+    ///
+    /// True colors are mapped to the nearest color of the ANSI 256-color
+    /// palette (or of the 16 standard colors), and ANSI colors to the nearest
+    /// of the 16 standard colors. Colors already supported by `level` are left
+    /// unchanged. The 16 standard colors are assumed to have their xterm
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::style::{Color, ColorSupport};
+    ///
+    /// let rgb = Color::Rgb(255, 0, 0);
+    /// assert_eq!(rgb.downgrade(ColorSupport::TrueColor), rgb);
+    /// assert_eq!(rgb.downgrade(ColorSupport::Ansi256), Color::Ansi(196));
+    /// assert_eq!(rgb.downgrade(ColorSupport::Basic16), Color::LightRed);
+    ///
+    /// let gray = Color::Rgb(128, 128, 128);
+    /// assert_eq!(gray.downgrade(ColorSupport::Ansi256), Color::Ansi(244));
+    /// assert_eq!(gray.downgrade(ColorSupport::Basic16), Color::LightBlack);
+    ///
+    /// let teal = Color::Rgb(0, 135, 135);
+    /// assert_eq!(teal.downgrade(ColorSupport::Ansi256), Color::Ansi(30));
+    ///
+    /// let basic = ColorSupport::Basic16;
+    /// assert_eq!(Color::Ansi(21).downgrade(basic), Color::Blue);
+    /// assert_eq!(Color::Ansi(232).downgrade(basic), Color::Black);
+    /// assert_eq!(Color::Ansi(3).downgrade(basic), Color::Yellow);
+    /// assert_eq!(Color::Cyan.downgrade(basic), Color::Cyan);
+    /// ```
+    pub fn downgrade(self, level: ColorSupport) -> Self
+    {
+        match (self, level) {
+            (Self::Rgb(r, g, b), ColorSupport::Ansi256) => {
+                Self::Ansi(nearest_ansi((r, g, b)))
+            },
+            (Self::Rgb(r, g, b), ColorSupport::Basic16) => {
+                nearest_basic((r, g, b))
+            },
+            (Self::Ansi(c), ColorSupport::Basic16) => {
+                nearest_basic(ansi_to_rgb(c))
+            },
+            _ => self,
+        }
+    }
+
+    /// Creates an RGB color from hue (in degrees), saturation and lightness.
+    ///
+    /// The hue wraps around, so that e.g. `-120.0` and `240.0` are the same.
+    /// Saturation and lightness are clamped to `0.0..=1.0`. `NaN`s are treated
+    /// as `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::style::Color;
+    ///
+    /// assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::Rgb(255, 0, 0));
+    /// assert_eq!(Color::from_hsl(120.0, 1.0, 0.25), Color::Rgb(0, 128, 0));
+    /// assert_eq!(Color::from_hsl(240.0, 0.5, 0.5), Color::Rgb(64, 64, 191));
+    /// assert_eq!(Color::from_hsl(-120.0, 2.0, 0.5), Color::Rgb(0, 0, 255));
+    /// assert_eq!(Color::from_hsl(90.0, 0.0, 1.0), Color::Rgb(255, 255, 255));
+    /// ```
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self
+    {
+        let (s, l) = (unit(s), unit(l));
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+
+        from_hue(h, chroma, l - chroma / 2.0)
+    }
+
+    /// Creates an RGB color from hue (in degrees), saturation and value.
+    ///
+    /// The hue wraps around, so that e.g. `-120.0` and `240.0` are the same.
+    /// Saturation and value are clamped to `0.0..=1.0`. `NaN`s are treated as
+    /// `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cwinui::style::Color;
+    ///
+    /// assert_eq!(Color::from_hsv(0.0, 1.0, 1.0), Color::Rgb(255, 0, 0));
+    /// assert_eq!(Color::from_hsv(60.0, 1.0, 0.5), Color::Rgb(128, 128, 0));
+    /// assert_eq!(Color::from_hsv(300.0, 0.5, 1.0), Color::Rgb(255, 128, 255));
+    /// assert_eq!(Color::from_hsv(540.0, 1.0, 1.0), Color::Rgb(0, 255, 255));
+    /// assert_eq!(Color::from_hsv(0.0, 0.0, -1.0), Color::Rgb(0, 0, 0));
+    /// ```
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Self
+    {
+        let (s, v) = (unit(s), unit(v));
+        let chroma = v * s;
+
+        from_hue(h, chroma, v - chroma)
+    }
+}
+
+/// Clamps `x` to `0.0..=1.0`, with `NaN` mapped to `0.0`.
+#[inline]
+fn unit(x: f32) -> f32
+{
+    if x.is_nan()
+        { 0.0 }
+        else { x.clamp(0.0, 1.0) }
+}
+
+/// Creates an RGB color from hue (in degrees), chroma and the amount of white
+/// (`min`) common to HSL and HSV.
+fn from_hue(h: f32, chroma: f32, min: f32) -> Color
+{
+    let h = if h.is_finite()
+        { h.rem_euclid(360.0) / 60.0 }
+        else { 0.0 };
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+
+    let (r, g, b) = match h as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let channel = |c: f32| ((c + min) * 255.0).round().clamp(0.0, 255.0) as u8;
+
+    Color::Rgb(channel(r), channel(g), channel(b))
+}
+
+/// Level of color support of a terminal.
+///
+/// See [`Color::downgrade`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, std::hash::Hash)]
+pub enum ColorSupport {
+    /// True colors (24-bit RGB) and all the colors below.
+    #[default]
+    TrueColor,
+    /// ANSI 256 colors and the 16 standard colors.
+    Ansi256,
+    /// Only the 16 standard colors.
+    Basic16,
+}
+
+/// The 16 standard colors with their xterm values.
+const BASIC_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black,        (0, 0, 0)),
+    (Color::Red,          (205, 0, 0)),
+    (Color::Green,        (0, 205, 0)),
+    (Color::Yellow,       (205, 205, 0)),
+    (Color::Blue,         (0, 0, 238)),
+    (Color::Magenta,      (205, 0, 205)),
+    (Color::Cyan,         (0, 205, 205)),
+    (Color::White,        (229, 229, 229)),
+    (Color::LightBlack,   (127, 127, 127)),
+    (Color::LightRed,     (255, 0, 0)),
+    (Color::LightGreen,   (0, 255, 0)),
+    (Color::LightYellow,  (255, 255, 0)),
+    (Color::LightBlue,    (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan,    (0, 255, 255)),
+    (Color::LightWhite,   (255, 255, 255)),
+];
+
+/// Channel values of the 6x6x6 color cube of the ANSI 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Squared euclidean distance of two RGB colors.
+#[inline]
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32
+{
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// Gets the RGB value of an ANSI 256-color palette entry.
+fn ansi_to_rgb(c: u8) -> (u8, u8, u8)
+{
+    match c {
+        0..=15 => BASIC_COLORS[c as usize].1,
+        16..=231 => {
+            let c = c - 16;
+            (
+                CUBE_LEVELS[c as usize / 36],
+                CUBE_LEVELS[c as usize / 6 % 6],
+                CUBE_LEVELS[c as usize % 6],
+            )
+        },
+        _ => {
+            let level = 8 + (c - 232) * 10;
+            (level, level, level)
+        },
+    }
+}
+
+/// Finds the nearest entry of the ANSI 256-color palette, leaving out the 16
+/// standard colors, as their values differ between terminals.
+fn nearest_ansi(rgb: (u8, u8, u8)) -> u8
+{
+    // Nearest level of the color cube, for each channel separately.
+    let cube_idx = |v: u8| match v {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (v - 35) / 40,
+    };
+    let (r, g, b) = (cube_idx(rgb.0), cube_idx(rgb.1), cube_idx(rgb.2));
+    let cube = 16 + 36 * r + 6 * g + b;
+
+    // Nearest level of the grayscale ramp (8, 18, ..., 238) to the average.
+    let avg = (rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3;
+    let gray_idx = if avg > 238
+        { 23 }
+        else { (avg.saturating_sub(3) / 10) as u8 };
+    let gray = 232 + gray_idx;
+
+    if distance(ansi_to_rgb(gray), rgb) < distance(ansi_to_rgb(cube), rgb)
+        { gray }
+        else { cube }
+}
+
+/// Finds the nearest of the 16 standard colors.
+fn nearest_basic(rgb: (u8, u8, u8)) -> Color
+{
+    BASIC_COLORS.iter()
+        .min_by_key(|&&(_, value)| distance(value, rgb))
+        .map(|&(color, _)| color)
+        .unwrap_or_default()
+}
+
 /// `&str` with attached `Style`.
 ///
 /// For owned version, see [`StyledString`](crate::alloc::string::StyledString).
@@ -149,18 +480,105 @@ impl<'s> StyledStr<'s> {
             style: self.style,
         }
     }
+
+    /// Slices the contained `str` by display columns, clones the [`Style`] and
+    /// constructs a new `StyledStr`.
+    ///
+    /// Wide characters that do not fit into `cols` entirely are left out.
+    /// Zero-width characters (e.g. combining marks) stay attached to the
+    /// preceding character.
+    ///
+    /// ```
+    /// use cwinui::style::StyledStr;
+    ///
+    /// let s = StyledStr::from("ab日本c");
+    ///
+    /// assert_eq!(s.slice_cols(1..4).content, "b日");
+    /// assert_eq!(s.slice_cols(0..3).content, "ab");
+    /// assert_eq!(s.slice_cols(3..7).content, "本c");
+    ///
+    /// let s = StyledStr::from("e\u{301}xy");
+    ///
+    /// assert_eq!(s.slice_cols(0..1).content, "e\u{301}");
+    /// assert_eq!(s.slice_cols(1..3).content, "xy");
+    /// ```
+    pub fn slice_cols(&self, cols: std::ops::Range<usize>) -> Self
+    {
+        let mut start = if cols.start == 0 { Some(0) } else { None };
+        let mut end = self.content.len();
+        let mut col = 0;
+
+        for (idx, c) in self.content.char_indices() {
+            let width = char_width(c);
+
+            if width == 0 {
+                continue;
+            }
+            if col + width > cols.end {
+                end = idx;
+                break;
+            }
+            if start.is_none() && col >= cols.start {
+                start = Some(idx);
+            }
+
+            col += width;
+        }
+
+        let start = start.unwrap_or(end);
+
+        Self {
+            content: &self.content[start..end],
+            style: self.style,
+        }
+    }
+
+    /// Iterates over the lines of the contained `str`, with the same
+    /// [`Style`].
+    ///
+    /// Lines are split the same way as with [`str::lines`], i.e. on `'\n'` or
+    /// `"\r\n"`, and a trailing newline does not start an empty line.
+    ///
+    /// ```
+    /// use cwinui::style::{Color, StyledStr, WithStyle};
+    ///
+    /// let s = "one\ntwo\r\n\nfour\n".with_style(|s| s.fg(Color::Red));
+    /// let lines: Vec<StyledStr> = s.lines().collect();
+    ///
+    /// assert_eq!(lines.len(), 4);
+    /// assert_eq!(lines[1].content, "two");
+    /// assert_eq!(lines[2].content, "");
+    /// assert!(lines.iter().all(|line| line.style == s.style));
+    /// ```
+    #[inline]
+    pub fn lines(&self) -> impl Iterator<Item = StyledStr<'s>>
+    {
+        let style = self.style;
+
+        self.content.lines()
+            .map(move |content| StyledStr { content, style })
+    }
+}
+
+/// Writes the content, ignoring the style.
+impl std::fmt::Display for StyledStr<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str(self.content)
+    }
 }
 
 /// Style-enhanced `AsRef<str>`.
 pub trait AsStyledStr {
-    fn as_styled_str(&self) -> StyledStr;
+    fn as_styled_str(&self) -> StyledStr<'_>;
 }
 
 impl<T> AsStyledStr for T
 where
     T: AsRef<str>
 {
-    fn as_styled_str(&self) -> StyledStr
+    fn as_styled_str(&self) -> StyledStr<'_>
     {
         StyledStr {
             content: self.as_ref(),
@@ -170,7 +588,7 @@ where
 }
 
 impl<'a> AsStyledStr for StyledStr<'a> {
-    fn as_styled_str(&self) -> StyledStr
+    fn as_styled_str(&self) -> StyledStr<'_>
     {
         *self
     }
@@ -28,3 +28,48 @@ macro_rules! min {
     }
 }
 pub(crate) use min;
+
+
+/// Display width of `c` in terminal columns.
+///
+/// Characters without a defined width (control characters) are assumed to
+/// take up a single cell.
+#[inline]
+pub(crate) fn char_width(c: char) -> usize
+{
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(1)
+}
+
+/// Display width of `s` in terminal columns.
+#[inline]
+pub(crate) fn str_width(s: &str) -> usize
+{
+    s.chars().map(char_width).sum()
+}
+
+/// Finds the longest prefix of `s` that fits into `max_width` columns, with
+/// tabs expanded to multiples of `tab_width`.
+///
+/// Returns the length of the prefix in bytes. Zero-width characters trailing
+/// the last fitting character are included in the prefix.
+#[inline]
+pub(crate) fn fit_width(s: &str, max_width: usize, tab_width: usize) -> usize
+{
+    let mut col = 0;
+
+    for (idx, c) in s.char_indices() {
+        let next = if c == '\t' {
+            (col / tab_width + 1) * tab_width
+        } else {
+            col + char_width(c)
+        };
+
+        if next > max_width {
+            return idx;
+        }
+
+        col = next;
+    }
+
+    s.len()
+}
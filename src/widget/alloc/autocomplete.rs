@@ -0,0 +1,232 @@
+use termion::event::{Event, Key};
+
+use super::{InputLine, InteractiveWidget, Menu};
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::render::{Render, Draw};
+
+
+/// Source of the suggestions of an [`Autocomplete`].
+#[derive(Debug, Clone)]
+enum Source {
+    /// Items containing the input.
+    List(Vec<String>),
+    /// Function computing the suggestions for the input.
+    Provider(fn(&str) -> Vec<String>),
+}
+
+/// [`InputLine`] with a dropdown [`Menu`] of suggestions.
+///
+/// The suggestions are recomputed after each edit of a non-empty input, and
+/// drawn below the input. The height of the dropdown is capped to the rest of
+/// the paint area.
+///
+/// Up and Down move the selection in the dropdown, Enter replaces the input
+/// with the selected suggestion and Esc dismisses the dropdown. Other events
+/// are passed to the input.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{Autocomplete, InteractiveWidget};
+///
+/// let mut autocomplete = Autocomplete::new(&["apple", "grape", "pear"]);
+/// autocomplete.process_event(Event::Key(Key::Char('p')));
+/// autocomplete.process_event(Event::Key(Key::Char('e')));
+///
+/// let mut backend = MemBackend::new(8, 2);
+/// backend.render(|buf| autocomplete.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// // Only the first of the suggestions fits below the input.
+/// assert_eq!(backend.to_string(), "pe      \n* grape ");
+///
+/// autocomplete.process_event(Event::Key(Key::Down));
+/// assert_eq!(autocomplete.selected(), Some("pear"));
+/// autocomplete.process_event(Event::Key(Key::Char('\n')));
+/// assert_eq!(autocomplete.text(), "pear");
+/// assert_eq!(autocomplete.selected(), None);
+/// ```
+///
+/// The dropdown is cleared before the suggestions are drawn, so that it covers
+/// whatever was drawn below the input:
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::style::StyledChar;
+/// use cwinui::widget::{Autocomplete, Filler, InteractiveWidget};
+///
+/// let mut autocomplete = Autocomplete::new(&["apple", "grape", "pear"]);
+/// autocomplete.process_event(Event::Key(Key::Char('p')));
+/// autocomplete.process_event(Event::Key(Key::Char('e')));
+///
+/// let mut backend = MemBackend::new(8, 4);
+/// backend.render(|buf| {
+///     Filler(StyledChar::from('.')).draw(buf, buf.area());
+///     autocomplete.draw(buf, buf.area());
+/// });
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "pe      \n* grape \n  pear  \n........");
+/// ```
+///
+/// Accepted suggestions can be edited like typed input:
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::widget::{Autocomplete, InteractiveWidget};
+///
+/// let mut autocomplete = Autocomplete::new(&["café"]);
+/// autocomplete.process_event(Event::Key(Key::Char('c')));
+/// autocomplete.process_event(Event::Key(Key::Char('\n')));
+/// autocomplete.process_event(Event::Key(Key::Backspace));
+///
+/// assert_eq!(autocomplete.text(), "caf");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Autocomplete {
+    pub input: InputLine,
+    source: Source,
+    suggestions: Option<Menu>,
+}
+
+impl Autocomplete {
+    /// Creates a new `Autocomplete`, suggesting the `items` that contain the
+    /// input.
+    pub fn new(items: &[&str]) -> Self
+    {
+        Self {
+            input: InputLine::new(),
+            source: Source::List(
+                items.iter().map(|it| it.to_string()).collect()
+            ),
+            suggestions: None,
+        }
+    }
+
+    /// Creates a new `Autocomplete`, with the suggestions computed by
+    /// `provider`.
+    pub fn with_provider(provider: fn(&str) -> Vec<String>) -> Self
+    {
+        Self {
+            input: InputLine::new(),
+            source: Source::Provider(provider),
+            suggestions: None,
+        }
+    }
+
+    /// Gets the text of the input.
+    #[inline]
+    pub fn text(&self) -> &str
+    {
+        self.input.content()
+    }
+
+    /// Gets the selected suggestion, if the dropdown is shown.
+    #[inline]
+    pub fn selected(&self) -> Option<&str>
+    {
+        self.suggestions.as_ref()
+            .map(|menu| menu.selected())
+    }
+
+    /// Recomputes the suggestions for the current input.
+    fn update_suggestions(&mut self)
+    {
+        let text = self.input.content();
+
+        let suggestions = match &self.source {
+            _ if text.is_empty() => Vec::new(),
+            Source::List(items) => items.iter()
+                .filter(|it| it.contains(text))
+                .cloned()
+                .collect(),
+            Source::Provider(provider) => provider(text),
+        };
+
+        self.suggestions = if suggestions.is_empty() {
+            None
+        } else {
+            let suggestions: Vec<&str> = suggestions.iter()
+                .map(|it| it.as_str())
+                .collect();
+
+            Some(Menu::new(&suggestions))
+        };
+    }
+}
+
+impl<R: Render> Draw<R> for Autocomplete {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let (input_area, rest) = area.split_horiz_at(1);
+        self.input.draw(buf, input_area);
+
+        if let Some(menu) = &self.suggestions {
+            let height = std::cmp::min(
+                menu.items().len(),
+                rest.height as usize
+            ) as u16;
+            let (dropdown_area, _) = rest.split_horiz_at(height);
+
+            buf.fill(' ', dropdown_area);
+            menu.draw(buf, dropdown_area);
+        }
+    }
+}
+
+impl Proportional for Autocomplete {
+    /// Same width as the input, and at least one row for the input.
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: self.input.proportions().width,
+            height: Range::from(1),
+        }
+    }
+}
+
+impl InteractiveWidget for Autocomplete {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        match (e, &mut self.suggestions) {
+            (e @ Event::Key(Key::Up | Key::Down), Some(menu)) => {
+                menu.handle_event(e)
+            },
+            (Event::Key(Key::Char('\n')), Some(menu)) => {
+                let selected = menu.selected().to_string();
+
+                self.input.set_content(&selected);
+                self.suggestions = None;
+                true
+            },
+            (Event::Key(Key::Esc), Some(_)) => {
+                self.suggestions = None;
+                true
+            },
+            (e, _) => {
+                let prev = self.input.content().to_string();
+                let consumed = self.input.handle_event(e);
+
+                if self.input.content() != prev {
+                    self.update_suggestions();
+                }
+
+                consumed
+            },
+        }
+    }
+}
@@ -7,8 +7,47 @@ use crate::render::Render;
 
 
 /// A buffered canvas that allows widgets to draw onto it.
+///
+/// Cells containing the `transparent` character (if any) are skipped when
+/// the canvas is drawn, so that whatever is behind them stays visible.
+///
+/// With `forward_cursor` set, the cursor of the canvas is forwarded to the
+/// target when the canvas is drawn. The cursor is hidden on the target when it
+/// is hidden on the canvas or falls outside of the drawn part of the canvas.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::layout::{Dim, Pos};
+/// use cwinui::style::StyledChar;
+/// use cwinui::widget::{Backdrop, Canvas, Filler};
+///
+/// let mut sprite = Canvas::new(Dim { width: 3, height: 2 })
+///     .transparent(Some('\0'));
+/// {
+///     let mut sprite_buf = sprite.renderer();
+///     sprite_buf.fill('\0', sprite_buf.area());
+///     sprite_buf.set_char(Pos { x: 1, y: 0 }, 'o');
+///     sprite_buf.set_char(Pos { x: 0, y: 1 }, '/');
+///     sprite_buf.set_char(Pos { x: 2, y: 1 }, '\\');
+/// }
+///
+/// let scene = Backdrop {
+///     fg: sprite,
+///     bg: Filler(StyledChar::from('.')),
+/// };
+/// let mut backend = MemBackend::new(4, 2);
+/// backend.render(|buf| scene.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), ".o..\n/.\\.");
+/// ```
 #[derive(Clone)]
 pub struct Canvas {
+    pub transparent: Option<char>,
+    pub forward_cursor: bool,
     width: u16,
     height: u16,
     chars: Vec<char>,
@@ -27,16 +66,115 @@ impl Canvas {
             height: dimensions.height,
             chars: vec![' '; size],
             styles: vec![Style::default().clean(); size],
-            cursor: Cursor { x: 0, y: 0, hidden: true },
+            cursor: Cursor::new(),
+            transparent: None,
+            forward_cursor: false,
         }
     }
 
+    /// Adjusts the character that is treated as transparent when drawing.
+    #[inline]
+    pub fn transparent(mut self, c: Option<char>) -> Self
+    {
+        self.transparent = c;
+
+        self
+    }
+
+    /// Adjusts the option to forward the cursor to the target.
+    ///
+    /// ```
+    /// use cwinui::backend::{Backend, WriterBackend};
+    /// use cwinui::render::{Draw, Render};
+    /// use cwinui::layout::{Area, Dim, Pos};
+    /// use cwinui::widget::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(Dim { width: 4, height: 1 })
+    ///     .forward_cursor(true);
+    /// {
+    ///     let mut canvas_buf = canvas.renderer();
+    ///     canvas_buf.move_cursor(Pos { x: 2, y: 0 });
+    ///     canvas_buf.show_cursor();
+    /// }
+    ///
+    /// let mut backend = WriterBackend::new(Vec::new(), 8, 3);
+    /// backend.render(|buf| {
+    ///     canvas.draw(buf, Area { x: 3, y: 1, width: 4, height: 1 })
+    /// });
+    /// backend.flush().unwrap();
+    ///
+    /// // The cursor is moved down by 1 row and right by 5 columns, and shown.
+    /// let output = String::from_utf8_lossy(backend.writer());
+    /// assert!(output.ends_with("\x1b[1B\x1b[5C\x1b[?25h"));
+    /// ```
+    #[inline]
+    pub fn forward_cursor(mut self, flag: bool) -> Self
+    {
+        self.forward_cursor = flag;
+
+        self
+    }
+
     /// Exposes the `Render` interface.
     #[inline]
     pub fn renderer(&mut self) -> impl Render + '_
+    {
+        self.buffer()
+    }
+
+    /// Exposes the underlying [`Buffer`].
+    #[inline]
+    pub(crate) fn buffer(&mut self) -> Buffer<'_>
     {
         Buffer::new(self.width, self.height, &mut self.chars, &mut self.styles, &mut self.cursor)
     }
+
+    /// Draws the part of the canvas starting at `offset` into `area`.
+    pub(crate) fn draw_from<R: Render>(
+        &self,
+        buf: &mut R,
+        area: Area,
+        offset: Pos
+    )
+    {
+        let width = std::cmp::min(
+            area.width,
+            self.width.saturating_sub(offset.x)
+        );
+        let height = std::cmp::min(
+            area.height,
+            self.height.saturating_sub(offset.y)
+        );
+
+        // FIXME: very inefficient due to bounds checking, needs to be done via
+        // diffing or some other method on `Render` instead.
+        // Also, having separate style and char bufs seems inefficient here.
+        for y in 0..height {
+            for x in 0..width {
+                let src = Pos { x, y } + offset;
+                let idx = offset!(src.x, src.y, self.width);
+                if self.transparent == Some(self.chars[idx]) {
+                    continue;
+                }
+                let c = self.chars[idx]
+                    .with_style(|_| self.styles[idx]);
+                buf.set_char(Pos { x: x + area.x, y: y + area.y }, c);
+            }
+        }
+
+        if self.forward_cursor {
+            let cursor = Pos { x: self.cursor.x, y: self.cursor.y };
+            let window = Area::from_parts(offset, Dim { width, height });
+
+            if !self.cursor.hidden && window.contains_pos(cursor) {
+                buf.set_cursor_shape(self.cursor.shape);
+                buf.move_cursor(cursor - offset + area.top_left());
+                buf.show_cursor();
+            } else {
+                buf.hide_cursor();
+            }
+        }
+    }
 }
 
 impl std::fmt::Debug for Canvas {
@@ -45,29 +183,17 @@ impl std::fmt::Debug for Canvas {
         f.debug_struct("Canvas")
             .field("width", &self.width)
             .field("height", &self.height)
+            .field("transparent", &self.transparent)
+            .field("forward_cursor", &self.forward_cursor)
             .finish()
     }
 }
 
 impl<R: Render> Draw<R> for Canvas {
+    #[inline]
     fn draw(&self, buf: &mut R, area: Area)
     {
-        let width = std::cmp::min(area.width, self.width);
-        let height = std::cmp::min(area.height, self.height);
-
-        // FIXME: very inefficient due to bounds checking, needs to be done via
-        // diffing or some other method on `Render` instead.
-        // Also, having separate style and char bufs seems inefficient here.
-        for y in 0..height {
-            for x in 0..width {
-                let offset = offset!(x, y, self.width);
-                let c = self.chars[offset]
-                    .with_style(|_| self.styles[offset]);
-                buf.set_char(Pos { x: x + area.x, y: y + area.y }, c);
-            }
-        }
-
-        // NOTE: we ignore cursors.
+        self.draw_from(buf, area, Pos::ZERO);
     }
 }
 
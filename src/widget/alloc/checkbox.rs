@@ -0,0 +1,164 @@
+use std::cell::Cell;
+
+use termion::event::{Event, Key, MouseButton, MouseEvent};
+
+use super::InteractiveWidget;
+use crate::Pos;
+use crate::alloc::string::StyledString;
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::render::{Render, Draw};
+use crate::style::{Style, StyledChar};
+use crate::util::str_width;
+use crate::widget::mouse_pos;
+
+
+/// Configuration options for theming [`Checkbox`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub checked: StyledChar,
+    pub unchecked: StyledChar,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    pub const fn default() -> Self
+    {
+        Self {
+            checked: StyledChar { content: 'x', style: Style::default() },
+            unchecked: StyledChar { content: ' ', style: Style::default() },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Labeled checkbox, toggled by space or a mouse click.
+///
+/// Drawn as `[x] Label`. Clicks are mapped using the last drawn area.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key, MouseButton, MouseEvent};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{Checkbox, InteractiveWidget};
+///
+/// let mut checkbox = Checkbox::new("Remember");
+/// let mut backend = MemBackend::new(14, 1);
+///
+/// checkbox.process_event(Event::Key(Key::Char(' ')));
+/// backend.render(|buf| checkbox.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "[x] Remember  ");
+///
+/// // Terminal coordinates start at 1.
+/// let click = |x| Event::Mouse(MouseEvent::Press(MouseButton::Left, x, 1));
+///
+/// assert!(checkbox.handle_event(click(6)));
+/// assert!(!checkbox.is_checked());
+/// assert!(!checkbox.handle_event(click(15)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Checkbox {
+    pub label: StyledString,
+    pub theme: Theme,
+    checked: bool,
+    // HACK: FIXME: this is state related purely to drawing.
+    area: Cell<Area>,
+}
+
+impl Checkbox {
+    /// Creates a new unchecked `Checkbox`.
+    pub fn new<T: Into<StyledString>>(label: T) -> Self
+    {
+        Self {
+            label: label.into(),
+            theme: Theme::default(),
+            checked: false,
+            area: Cell::new(Area::default()),
+        }
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Checks if the checkbox is checked.
+    #[inline]
+    pub fn is_checked(&self) -> bool
+    {
+        self.checked
+    }
+
+    /// Sets the checked state.
+    #[inline]
+    pub fn set_checked(&mut self, checked: bool)
+    {
+        self.checked = checked;
+    }
+}
+
+impl<R: Render> Draw<R> for Checkbox {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        self.area.set(area);
+
+        if area.is_collapsed() {
+            return;
+        }
+
+        let marker = if self.checked
+            { self.theme.checked }
+            else { self.theme.unchecked };
+
+        buf.putc(Pos::ZERO, '[', area);
+        buf.putc(Pos { x: 1, y: 0 }, marker, area);
+        buf.print(Pos { x: 2, y: 0 }, "] ", area);
+        buf.print(Pos { x: 4, y: 0 }, &self.label, area);
+    }
+}
+
+impl Proportional for Checkbox {
+    fn proportions(&self) -> Proportions
+    {
+        let width = str_width(&self.label.content) + 4;
+
+        Proportions {
+            width: Range::fixed(std::cmp::min(width, u16::MAX as usize) as u16),
+            height: Range::fixed(1),
+        }
+    }
+}
+
+impl InteractiveWidget for Checkbox {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        match e {
+            Event::Key(Key::Char(' ')) => self.checked = !self.checked,
+            Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y))
+                if self.area.get().contains_pos(mouse_pos(x, y)) =>
+            {
+                self.checked = !self.checked;
+            },
+            _ => return false,
+        }
+
+        true
+    }
+}
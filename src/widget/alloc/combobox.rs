@@ -0,0 +1,290 @@
+use std::cell::Cell;
+
+use termion::event::{Event, Key, MouseButton, MouseEvent};
+
+use super::{InteractiveWidget, Menu};
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::render::{Render, Draw};
+use crate::style::{Style, WithStyle};
+use crate::widget::mouse_pos;
+use crate::Pos;
+
+
+/// Configuration options for theming [`ComboBox`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    /// Style of the row with the selected value.
+    pub value_style: Style,
+    /// Character drawn at the end of the row with the selected value.
+    pub indicator: char,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    pub const fn default() -> Self
+    {
+        Self {
+            value_style: Style::default(),
+            indicator: '▾',
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Dropdown list showing the selected value in a single row.
+///
+/// Enter or a click on the row opens the list, drawn as a [`Menu`] below the
+/// row. The height of the list is clamped to the rest of the paint area, and
+/// the list scrolls if the items do not fit.
+///
+/// While the list is open, the events are passed to the menu. Enter or a click
+/// on an item selects the item and closes the list, while Esc, a click on the
+/// row or a click elsewhere closes the list, keeping the previous selection.
+///
+/// The click positions are mapped using the last drawn area, the same way as
+/// in [`Menu`].
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{ComboBox, InteractiveWidget};
+///
+/// let mut combobox = ComboBox::new(&["red", "green", "blue"]);
+/// let mut backend = MemBackend::new(8, 3);
+///
+/// combobox.process_event(Event::Key(Key::Char('\n')));
+/// combobox.process_event(Event::Key(Key::Down));
+/// assert!(combobox.is_open());
+/// assert_eq!(combobox.selected(), Some("red"));
+///
+/// // Only two of the items fit below the row.
+/// backend.render(|buf| combobox.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "red    ▾\n  red   \n* green ");
+///
+/// combobox.process_event(Event::Key(Key::Char('\n')));
+/// assert!(!combobox.is_open());
+/// assert_eq!(combobox.selected(), Some("green"));
+///
+/// backend.render(|buf| combobox.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "green  ▾\n        \n        ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ComboBox {
+    pub theme: Theme,
+    menu: Menu,
+    selected_idx: usize,
+    open: bool,
+    // HACK: FIXME: this is state related purely to drawing.
+    row_area: Cell<Area>,
+    // HACK: FIXME: this is state related purely to drawing.
+    list_area: Cell<Area>,
+}
+
+impl ComboBox {
+    /// Creates a new closed `ComboBox`, with the first item selected.
+    pub fn new(items: &[&str]) -> Self
+    {
+        Self {
+            theme: Theme::default(),
+            menu: Menu::new(items),
+            selected_idx: 0,
+            open: false,
+            row_area: Cell::new(Area::default()),
+            list_area: Cell::new(Area::default()),
+        }
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Adjusts the theme of the list.
+    #[inline]
+    pub fn menu_theme(mut self, theme: super::menu::Theme) -> Self
+    {
+        self.menu = self.menu.theme(theme);
+
+        self
+    }
+
+    /// Gets a reference to the items.
+    #[inline]
+    pub fn items(&self) -> &[String]
+    {
+        self.menu.items()
+    }
+
+    /// Gets the selected item, or `None` if there are no items.
+    ///
+    /// While the list is open, this is still the previously selected item.
+    #[inline]
+    pub fn selected(&self) -> Option<&str>
+    {
+        self.items()
+            .get(self.selected_idx)
+            .map(|it| it.as_str())
+    }
+
+    /// Gets the index of the selected item.
+    #[inline]
+    pub fn selected_idx(&self) -> usize
+    {
+        self.selected_idx
+    }
+
+    /// Selects the item at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// When `idx` is out of bounds.
+    #[inline]
+    pub fn select(&mut self, idx: usize)
+    {
+        self.menu.select(idx);
+        self.selected_idx = idx;
+    }
+
+    /// Checks if the list is open.
+    #[inline]
+    pub fn is_open(&self) -> bool
+    {
+        self.open
+    }
+
+    /// Opens the list, with the selected item highlighted.
+    ///
+    /// Lists without any items are not opened.
+    pub fn open(&mut self)
+    {
+        if self.items().is_empty() {
+            return;
+        }
+
+        self.menu.select(self.selected_idx);
+        self.open = true;
+    }
+
+    /// Closes the list, keeping the previous selection.
+    #[inline]
+    pub fn close(&mut self)
+    {
+        self.open = false;
+    }
+
+    /// Selects the item highlighted in the list and closes the list.
+    #[inline]
+    fn commit(&mut self)
+    {
+        self.selected_idx = self.menu.selected_idx();
+        self.open = false;
+    }
+}
+
+impl<R: Render> Draw<R> for ComboBox {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let (row, rest) = area.split_horiz_at(1);
+        self.row_area.set(row);
+
+        buf.fill(' '.with_style(|_| self.theme.value_style), row);
+
+        let (value_area, _) = row.split_vert_at(row.width.saturating_sub(2));
+        if let Some(value) = self.selected() {
+            let value = value.with_style(|_| self.theme.value_style);
+            buf.print(Pos::ZERO, value, value_area);
+        }
+
+        let indicator = self.theme.indicator
+            .with_style(|_| self.theme.value_style);
+        buf.putc(Pos { x: row.width - 1, y: 0 }, indicator, row);
+
+        if !self.open {
+            self.list_area.set(Area::default());
+            return;
+        }
+
+        let height = std::cmp::min(
+            self.items().len(),
+            rest.height as usize
+        ) as u16;
+        let (list_area, _) = rest.split_horiz_at(height);
+        self.list_area.set(list_area);
+
+        buf.fill(' ', list_area);
+        self.menu.draw(buf, list_area);
+    }
+}
+
+impl Proportional for ComboBox {
+    /// Flexible width, and at least one row for the selected value.
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::from(1),
+        }
+    }
+}
+
+impl InteractiveWidget for ComboBox {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        if !self.open {
+            match e {
+                Event::Key(Key::Char('\n')) if !self.items().is_empty() => {
+                    self.open();
+                },
+                Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y))
+                    if !self.items().is_empty()
+                        && self.row_area.get().contains_pos(mouse_pos(x, y)) =>
+                {
+                    self.open();
+                },
+                _ => return false,
+            }
+
+            return true;
+        }
+
+        match e {
+            Event::Key(Key::Char('\n')) => self.commit(),
+            Event::Key(Key::Esc) => self.close(),
+            e @ Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y)) => {
+                if self.list_area.get().contains_pos(mouse_pos(x, y)) {
+                    self.menu.process_event(e);
+                    self.commit();
+                } else {
+                    self.close();
+                }
+            },
+            e => return self.menu.handle_event(e),
+        }
+
+        true
+    }
+}
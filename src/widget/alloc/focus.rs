@@ -0,0 +1,159 @@
+use termion::event::{Event, Key};
+
+use super::InteractiveWidget;
+
+
+/// Routes events to one of several interactive widgets, the focused one.
+///
+/// Tab and Shift-Tab move the focus to the next and the previous widget,
+/// wrapping around. All other events are forwarded to the focused widget.
+///
+/// The group borrows the widgets mutably, so it is meant to be short-lived:
+/// the focused index is kept by the application between events, e.g. to
+/// highlight the focused widget when drawing.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::widget::{FocusGroup, InputLine, InteractiveWidget};
+///
+/// let mut name = InputLine::new();
+/// let mut email = InputLine::new();
+/// let mut focused = 0;
+///
+/// for key in [Key::Char('a'), Key::Char('\t'), Key::Char('b')] {
+///     let mut group = FocusGroup::new(vec![&mut name, &mut email])
+///         .focus(focused);
+///     group.process_event(Event::Key(key));
+///     focused = group.focused();
+/// }
+///
+/// assert_eq!(focused, 1);
+/// assert_eq!(name.content(), "a");
+/// assert_eq!(email.content(), "b");
+/// ```
+///
+/// Events that the focused widget does not act on are reported as not
+/// consumed, so that they can be handled elsewhere:
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::widget::{FocusGroup, InputLine, InteractiveWidget, Menu};
+///
+/// let mut search = InputLine::new();
+/// let mut results = Menu::new(&["a", "b"]);
+/// let mut group = FocusGroup::new(vec![&mut search, &mut results]);
+///
+/// assert!(group.handle_event(Event::Key(Key::Char('x'))));
+/// assert!(!group.handle_event(Event::Key(Key::Up)));
+///
+/// group.focus_next();
+/// assert!(group.handle_event(Event::Key(Key::Down)));
+/// assert!(!group.handle_event(Event::Key(Key::Down)));
+/// assert!(!group.handle_event(Event::Key(Key::Esc)));
+/// ```
+pub struct FocusGroup<'a> {
+    widgets: Vec<&'a mut dyn InteractiveWidget>,
+    focused: usize,
+}
+
+impl std::fmt::Debug for FocusGroup<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+    {
+        write!(
+            f,
+            "FocusGroup({} widgets, focused: {})",
+            self.widgets.len(),
+            self.focused
+        )
+    }
+}
+
+impl<'a> FocusGroup<'a> {
+    /// Creates a new `FocusGroup`, with the first widget focused.
+    pub fn new(widgets: Vec<&'a mut dyn InteractiveWidget>) -> Self
+    {
+        Self {
+            widgets,
+            focused: 0,
+        }
+    }
+
+    /// Focuses the widget at `idx`.
+    ///
+    /// The index is clamped to the last widget.
+    #[inline]
+    pub fn focus(mut self, idx: usize) -> Self
+    {
+        self.focused = std::cmp::min(idx, self.widgets.len().saturating_sub(1));
+
+        self
+    }
+
+    /// Gets the index of the focused widget.
+    #[inline]
+    pub fn focused(&self) -> usize
+    {
+        self.focused
+    }
+
+    /// Gets a mutable reference to the focused widget, if there are any
+    /// widgets.
+    #[inline]
+    pub fn focused_widget(&mut self) -> Option<&mut dyn InteractiveWidget>
+    {
+        match self.widgets.get_mut(self.focused) {
+            Some(w) => Some(&mut **w),
+            None => None,
+        }
+    }
+
+    /// Moves the focus to the next widget, wrapping around.
+    #[inline]
+    pub fn focus_next(&mut self)
+    {
+        if !self.widgets.is_empty() {
+            self.focused = (self.focused + 1) % self.widgets.len();
+        }
+    }
+
+    /// Moves the focus to the previous widget, wrapping around.
+    #[inline]
+    pub fn focus_prev(&mut self)
+    {
+        if !self.widgets.is_empty() {
+            self.focused = self.focused
+                .checked_sub(1)
+                .unwrap_or(self.widgets.len() - 1);
+        }
+    }
+}
+
+impl InteractiveWidget for FocusGroup<'_> {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    /// Tab and Shift-Tab are always consumed, as long as there are any
+    /// widgets. Other events are consumed if the focused widget consumes them.
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        if self.widgets.is_empty() {
+            return false;
+        }
+
+        match e {
+            Event::Key(Key::Char('\t')) => {
+                self.focus_next();
+                true
+            },
+            Event::Key(Key::BackTab) => {
+                self.focus_prev();
+                true
+            },
+            e => self.widgets[self.focused].handle_event(e),
+        }
+    }
+}
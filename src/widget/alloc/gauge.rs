@@ -0,0 +1,147 @@
+use crate::layout::{Area, Justify, Proportional, Proportions, Range};
+use crate::render::{Render, Draw};
+use crate::style::{Style, StyledChar, StyledStr, TextStyle};
+use crate::util::str_width;
+use crate::widget::progress::filled_width;
+use crate::Pos;
+
+
+/// Configuration options for theming [`Gauge`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub filled: StyledChar,
+    pub empty: StyledChar,
+    /// Style of the label where it covers the filled part.
+    pub label_filled: Style,
+    /// Style of the label where it covers the empty part.
+    pub label_empty: Style,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    #[inline]
+    pub const fn default() -> Self
+    {
+        Self {
+            filled: StyledChar { content: '#', style: Style::default() },
+            empty: StyledChar { content: ' ', style: Style::default() },
+            label_filled: Style::default().text_style(TextStyle::INVERT),
+            label_empty: Style::default(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Bar filling the paint area with a centered label.
+///
+/// Unless overridden, the label shows the percentage of the ratio. If the paint
+/// area is too narrow to fit the label, it is omitted.
+///
+/// `ratio` is expected to be within `0.0..=1.0`, values outside of the range
+/// are clamped.
+#[derive(Debug, Clone, Default)]
+pub struct Gauge {
+    pub ratio: f32,
+    pub theme: Theme,
+    label: Option<String>,
+}
+
+impl Gauge {
+    /// Creates a new `Gauge`.
+    #[inline]
+    pub const fn new(ratio: f32) -> Self
+    {
+        Self {
+            ratio,
+            theme: Theme::default(),
+            label: None,
+        }
+    }
+
+    /// Overrides the label. `None` shows the percentage.
+    #[inline]
+    pub fn label(mut self, label: Option<String>) -> Self
+    {
+        self.label = label;
+
+        self
+    }
+
+    /// Adjusts the theme of the `Gauge`.
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+}
+
+impl<R: Render> Draw<R> for Gauge {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let filled = filled_width(self.ratio, area.width);
+        let (filled_area, empty_area) = area.split_vert_at(filled);
+
+        buf.fill(self.theme.filled, filled_area);
+        buf.fill(self.theme.empty, empty_area);
+
+        // Label
+
+        let percentage;
+        let label = match &self.label {
+            Some(label) => label.as_str(),
+            None => {
+                let ratio = self.ratio.clamp(0., 1.);
+                percentage = format!("{}%", (ratio * 100.).round() as u8);
+                percentage.as_str()
+            },
+        };
+
+        let label_width = str_width(label);
+        if label_width > area.width as usize {
+            return;
+        }
+
+        buf.jprint(
+            StyledStr { content: label, style: self.theme.label_empty },
+            Justify::Center,
+            area
+        );
+
+        // Restyle the part of the label that covers the filled part.
+        let label_x = (area.width - label_width as u16) / 2;
+        if filled > label_x {
+            let covered = StyledStr {
+                content: label,
+                style: self.theme.label_filled,
+            }.slice_cols(0..(filled - label_x) as usize);
+
+            buf.print(
+                Pos { x: label_x, y: area.height.saturating_sub(1) / 2 },
+                covered,
+                area
+            );
+        }
+    }
+}
+
+impl Proportional for Gauge {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::from(1),
+        }
+    }
+}
@@ -2,9 +2,11 @@ use termion::event::{Event, Key};
 
 use super::InteractiveWidget;
 use crate::Pos;
+use crate::alloc::string::StyledString;
 use crate::layout::{Area, Proportional, Proportions};
 use crate::render::{Render, Draw};
-use crate::style::{StyledChar, Style, WithStyle};
+use crate::style::{Color, StyledChar, Style, WithStyle};
+use crate::util::char_width;
 
 
 /// Configuration options for theming [`InputLine`].
@@ -12,6 +14,8 @@ use crate::style::{StyledChar, Style, WithStyle};
 pub struct Theme {
     pub blank_c: StyledChar,
     pub input_style: Style,
+    /// Style of the input when it is not valid, see [`InputLine::validator`].
+    pub error_style: Style,
 }
 
 impl Theme {
@@ -21,6 +25,7 @@ impl Theme {
         Self {
             blank_c: StyledChar { content: ' ', style: Style::default() },
             input_style: Style::default(),
+            error_style: Style::default().fg(Color::Red),
         }
     }
 }
@@ -33,10 +38,69 @@ impl Default for Theme {
 }
 
 /// Primitive for drawing input fields.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::widget::InteractiveWidget;
+/// use cwinui::widget::InputLine;
+///
+/// let mut input = InputLine::new();
+///
+/// for key in [Key::Char('a'), Key::Char('c'), Key::Left, Key::Char('b')] {
+///     input.process_event(Event::Key(key));
+/// }
+///
+/// assert_eq!(input.content(), "abc");
+///
+/// input.process_event(Event::Key(Key::Delete));
+/// input.process_event(Event::Key(Key::Delete));
+///
+/// assert_eq!(input.content(), "ab");
+///
+/// input.process_event(Event::Key(Key::Home));
+/// input.process_event(Event::Key(Key::Char('_')));
+/// input.process_event(Event::Key(Key::End));
+/// input.process_event(Event::Key(Key::Char('_')));
+///
+/// assert_eq!(input.content(), "_ab_");
+/// ```
+///
+/// For password fields, the content can be drawn masked:
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{InputLine, InteractiveWidget};
+///
+/// let mut input = InputLine::new().mask(Some('*'));
+/// for c in "hunter2".chars() {
+///     input.process_event(Event::Key(Key::Char(c)));
+/// }
+///
+/// let mut backend = MemBackend::new(5, 1);
+/// backend.render(|buf| input.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "**** ");
+/// assert_eq!(input.content(), "hunter2");
+/// ```
 #[derive(Debug, Clone)]
 pub struct InputLine {
     pub theme: Theme,
     pub active: bool,
+    /// Character drawn in place of each character of the content.
+    ///
+    /// The mask has to be one column wide, other masks are drawn as `'*'`.
+    pub mask: Option<char>,
+    /// Maximum number of characters that can be typed in.
+    pub max_len: Option<usize>,
+    /// Text shown while the input is empty.
+    pub placeholder: Option<StyledString>,
+    validator: Option<fn(&str) -> bool>,
+    valid: bool,
     content: String,
     cursor_pos: u16,
 }
@@ -50,6 +114,11 @@ impl InputLine {
             cursor_pos: 0,
             theme: Theme::default(),
             active: false,
+            mask: None,
+            max_len: None,
+            placeholder: None,
+            validator: None,
+            valid: true,
         }
     }
 
@@ -61,6 +130,11 @@ impl InputLine {
             cursor_pos: 0,
             theme: Theme::default(),
             active: false,
+            mask: None,
+            max_len: None,
+            placeholder: None,
+            validator: None,
+            valid: true,
         }
     }
 
@@ -71,6 +145,51 @@ impl InputLine {
         &self.content
     }
 
+    /// Gets the position of the cursor, in characters.
+    #[inline]
+    pub fn cursor(&self) -> usize
+    {
+        self.cursor_pos as usize
+    }
+
+    /// Replaces the contents of the input and moves the cursor to the end.
+    ///
+    /// Control characters are left out, since they cannot be typed in.
+    ///
+    /// ```
+    /// use termion::event::{Event, Key};
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::{Draw, Render};
+    /// use cwinui::widget::{InputLine, InteractiveWidget};
+    ///
+    /// let mut input = InputLine::new();
+    /// input.set_content("日本語\n");
+    /// input.process_event(Event::Key(Key::Backspace));
+    /// assert_eq!(input.content(), "日本");
+    ///
+    /// // The view is scrolled so that the cursor stays in the area.
+    /// let mut backend = MemBackend::new(3, 1);
+    /// backend.render(|buf| input.draw(buf, buf.area()));
+    /// backend.flush().unwrap();
+    /// assert_eq!(backend.to_string(), "本 ");
+    /// ```
+    pub fn set_content(&mut self, content: &str)
+    {
+        self.content.clear();
+        self.content.extend(content.chars().filter(|c| !c.is_control()));
+        self.cursor_pos = self.len() as u16;
+        self.validate();
+    }
+
+    /// Checks if the content was accepted by the validator.
+    ///
+    /// Without a validator, the content is always valid.
+    #[inline]
+    pub fn is_valid(&self) -> bool
+    {
+        self.valid
+    }
+
     /// Adjusts the theme of the `InputLine`.
     #[inline]
     pub const fn theme(mut self, theme: Theme) -> Self
@@ -79,6 +198,161 @@ impl InputLine {
 
         self
     }
+
+    /// Adjusts the mask drawn in place of the content.
+    #[inline]
+    pub const fn mask(mut self, mask: Option<char>) -> Self
+    {
+        self.mask = mask;
+
+        self
+    }
+
+    /// Adjusts the maximum number of characters that can be typed in.
+    ///
+    /// Characters typed while the input is full are ignored. Deleting
+    /// characters is always possible.
+    ///
+    /// ```
+    /// use termion::event::{Event, Key};
+    /// use cwinui::widget::{InputLine, InteractiveWidget};
+    ///
+    /// let mut input = InputLine::new().max_len(Some(3));
+    /// for c in "abcd".chars() {
+    ///     input.process_event(Event::Key(Key::Char(c)));
+    /// }
+    /// assert_eq!(input.content(), "abc");
+    ///
+    /// input.process_event(Event::Key(Key::Backspace));
+    /// input.process_event(Event::Key(Key::Char('x')));
+    /// assert_eq!(input.content(), "abx");
+    /// ```
+    #[inline]
+    pub const fn max_len(mut self, max_len: Option<usize>) -> Self
+    {
+        self.max_len = max_len;
+
+        self
+    }
+
+    /// Adjusts the text shown while the input is empty.
+    ///
+    /// The placeholder is drawn in its own style and is not part of the
+    /// content.
+    ///
+    /// ```
+    /// use termion::event::{Event, Key};
+    /// use cwinui::backend::{Backend, MemBackend};
+    /// use cwinui::render::{Draw, Render};
+    /// use cwinui::style::{TextStyle, WithStyle};
+    /// use cwinui::widget::{InputLine, InteractiveWidget};
+    ///
+    /// let placeholder = "Search…".with_style(|s| s.text_style(TextStyle::DIM));
+    /// let mut input = InputLine::new().placeholder(Some(placeholder.into()));
+    /// let mut backend = MemBackend::new(5, 1);
+    ///
+    /// backend.render(|buf| input.draw(buf, buf.area()));
+    /// backend.flush().unwrap();
+    /// assert_eq!(backend.to_string(), "Searc");
+    /// assert_eq!(input.content(), "");
+    ///
+    /// input.process_event(Event::Key(Key::Char('a')));
+    /// backend.render(|buf| input.draw(buf, buf.area()));
+    /// backend.flush().unwrap();
+    /// assert_eq!(backend.to_string(), "a    ");
+    /// ```
+    #[inline]
+    pub fn placeholder(mut self, placeholder: Option<StyledString>) -> Self
+    {
+        self.placeholder = placeholder;
+
+        self
+    }
+
+    /// Adjusts the function that checks the content.
+    ///
+    /// The content is checked right away and after each edit. While it is not
+    /// valid, it is drawn with the error style of the theme.
+    ///
+    /// ```
+    /// use termion::event::{Event, Key};
+    /// use cwinui::widget::{InputLine, InteractiveWidget};
+    ///
+    /// let mut input = InputLine::new()
+    ///     .validator(Some(|s| s.contains('@')));
+    /// assert!(!input.is_valid());
+    ///
+    /// for c in "a@b".chars() {
+    ///     input.process_event(Event::Key(Key::Char(c)));
+    /// }
+    /// assert!(input.is_valid());
+    ///
+    /// input.process_event(Event::Key(Key::Home));
+    /// input.process_event(Event::Key(Key::Delete));
+    /// input.process_event(Event::Key(Key::Delete));
+    /// assert!(!input.is_valid());
+    /// ```
+    #[inline]
+    pub fn validator(mut self, validator: Option<fn(&str) -> bool>) -> Self
+    {
+        self.validator = validator;
+        self.validate();
+
+        self
+    }
+
+    /// Runs the validator on the content.
+    #[inline]
+    fn validate(&mut self)
+    {
+        self.valid = match self.validator {
+            Some(validator) => validator(&self.content),
+            None => true,
+        };
+    }
+
+    /// Gets the number of characters in the content.
+    #[inline]
+    fn len(&self) -> usize
+    {
+        // TODO: utf8 support (graphemes).
+        self.content.chars().count()
+    }
+
+    /// Gets the byte offset of the character at `idx`, or the length of the
+    /// content if it is past the end.
+    #[inline]
+    fn byte_offset(&self, idx: usize) -> usize
+    {
+        self.content.char_indices()
+            .nth(idx)
+            .map_or(self.content.len(), |(offset, _)| offset)
+    }
+
+    /// Checks if the maximum number of characters has been reached.
+    #[inline]
+    fn is_full(&self) -> bool
+    {
+        self.max_len.is_some_and(|max| self.len() >= max)
+    }
+
+    /// Gets the character to draw in place of `c`.
+    #[inline]
+    fn displayed(&self, c: char) -> char
+    {
+        match self.mask {
+            Some(mask) if char_width(mask) == 1 => mask,
+            Some(_) => '*',
+            None => c,
+        }
+    }
+}
+
+impl Default for InputLine {
+    fn default() -> Self
+    {
+        Self::new()
+    }
 }
 
 impl<R: Render> Draw<R> for InputLine {
@@ -91,37 +365,62 @@ impl<R: Render> Draw<R> for InputLine {
         // Draw the input.
 
         let width = area.width as usize;
-        // TODO: utf8 support (graphemes).
-        let input_len = self.content.len();
+        let chars: Vec<char> = self.content.chars()
+            .map(|c| self.displayed(c))
+            .collect();
+        let cursor_pos = self.cursor_pos as usize;
 
         buf.hfill(area.top_left(), self.theme.blank_c, width);
 
-        let capped_input_len = std::cmp::min(input_len, width - 1);
-        let end = std::cmp::max(self.cursor_pos as usize, capped_input_len);
-        let start = end.saturating_sub(width - 1);
-        // TODO: utf8 support (graphemes).
-        let visible_input = self.content[start..end]
-            .with_style(|_| self.theme.input_style);
+        if let Some(placeholder) = &self.placeholder {
+            if self.content.is_empty() {
+                buf.print(Pos::ZERO, placeholder, area);
+            }
+        }
+
+        // The visible input takes up all but the last column, and it ends
+        // either at the cursor or at the last character that fits.
+        let max_cols = width - 1;
+
+        let mut end = 0;
+        let mut cols = 0;
+        while end < chars.len() && cols + char_width(chars[end]) <= max_cols {
+            cols += char_width(chars[end]);
+            end += 1;
+        }
+        let end = std::cmp::max(cursor_pos, end);
+
+        let mut start = end;
+        let mut cols = 0;
+        while start > 0 && cols + char_width(chars[start - 1]) <= max_cols {
+            cols += char_width(chars[start - 1]);
+            start -= 1;
+        }
 
-        buf.print(Pos::ZERO, visible_input, area);
+        let visible_input: String = chars[start..end].iter().collect();
 
-        let cursor_moved = (self.cursor_pos as usize) < input_len;
-        if cursor_moved && input_len >= width {
+        let style = if self.valid
+            { self.theme.input_style }
+            else { self.theme.error_style };
+
+        buf.print(Pos::ZERO, visible_input.with_style(|_| style), area);
+
+        // The last column shows the character following the visible input,
+        // which is the one under the cursor when it is in the last column.
+        if end < chars.len() && char_width(chars[end]) == 1 {
             buf.putc_abs(
                 area.top_right().sub_x(1),
-                // TODO: utf8 support (graphemes).
-                self.content.chars().nth(self.cursor_pos as usize + 1)
-                    .unwrap()
-                    .with_style(|_| self.theme.input_style),
+                chars[end].with_style(|_| style),
             );
         }
 
         if self.active {
+            let cursor_col: usize = chars[start..cursor_pos].iter()
+                .map(|&c| char_width(c))
+                .sum();
+
             buf.move_cursor(Pos {
-                x: std::cmp::min(
-                    area.x + self.cursor_pos,
-                    area.x + area.width - 1,
-                ),
+                x: area.x + std::cmp::min(cursor_col, max_cols) as u16,
                 y: area.y
             });
             buf.show_cursor()
@@ -135,36 +434,53 @@ impl Proportional for InputLine {
         use crate::layout::Range;
 
         Proportions {
-            height: Range::flexible(),
-            width: Range::fixed(1),
+            width: Range::from(1),
+            height: Range::fixed(1),
         }
     }
 }
 
 impl InteractiveWidget for InputLine {
     fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
     {
         match e {
-            Event::Key(Key::Char(c)) => {
-                // TODO: utf8 support.
-                if c.is_ascii_alphanumeric()
-                    || c.is_ascii_punctuation()
-                    || c == ' '
-                {
-                    self.content.insert(self.cursor_pos as usize, c);
-                    self.cursor_pos += 1;
-                }
+            Event::Key(Key::Char(c)) if !self.is_full() && !c.is_control() => {
+                let offset = self.byte_offset(self.cursor_pos as usize);
+
+                self.content.insert(offset, c);
+                self.cursor_pos += 1;
+            },
+            Event::Key(Key::Backspace) if self.cursor_pos > 0 => {
+                self.cursor_pos -= 1;
+                let offset = self.byte_offset(self.cursor_pos as usize);
+
+                self.content.remove(offset);
+            },
+            Event::Key(Key::Left) if self.cursor_pos > 0 => {
+                self.cursor_pos -= 1;
             },
-            Event::Key(Key::Backspace) => {
-                if self.cursor_pos > 0 {
-                // TODO: utf8 support.
-                    self.content.remove(self.cursor_pos as usize - 1);
-                    self.cursor_pos -= 1;
-                }
+            Event::Key(Key::Right) if (self.cursor_pos as usize) < self.len() => {
+                self.cursor_pos += 1;
             },
-            // TODO: arrow keys
-            // TODO: Event::Key(Key::Delete) => {},
-            _ => (),
+            Event::Key(Key::Home) if self.cursor_pos > 0 => self.cursor_pos = 0,
+            Event::Key(Key::End) if (self.cursor_pos as usize) < self.len() => {
+                self.cursor_pos = self.len() as u16;
+            },
+            Event::Key(Key::Delete) if (self.cursor_pos as usize) < self.len() => {
+                let offset = self.byte_offset(self.cursor_pos as usize);
+
+                self.content.remove(offset);
+            },
+            _ => return false,
         }
+
+        self.validate();
+
+        true
     }
 }
@@ -0,0 +1,224 @@
+use std::cell::Cell;
+
+use termion::event::{Event, Key};
+
+use super::InteractiveWidget;
+use super::menu::scroll_to_item;
+use crate::alloc::string::StyledString;
+use crate::layout::{Area, Proportional, Proportions};
+use crate::render::{Render, Draw};
+use crate::style::{Style, TextStyle};
+use crate::Pos;
+
+
+/// Configuration options for theming [`ListBox`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    /// Marker in front of the selected items.
+    pub checked: &'static str,
+    /// Marker in front of the items that are not selected.
+    pub unchecked: &'static str,
+    /// Style of the focused item.
+    pub focused: Style,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    pub const fn default() -> Self
+    {
+        Self {
+            checked: "[x] ",
+            unchecked: "[ ] ",
+            focused: Style::default().text_style(TextStyle::INVERT),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// List of items that can be selected independently.
+///
+/// The focus is moved with the arrow keys, Home and End, and Space toggles the
+/// selection of the focused item. The focus and the selection are separate,
+/// i.e. moving the focus does not change the selection.
+///
+/// The view is scrolled the same way as in [`Menu`](super::Menu).
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{InteractiveWidget, ListBox};
+///
+/// let mut listbox = ListBox::new(&["a.txt", "b.txt", "c.txt"]);
+///
+/// listbox.process_event(Event::Key(Key::Char(' ')));
+/// listbox.process_event(Event::Key(Key::End));
+/// listbox.process_event(Event::Key(Key::Char(' ')));
+/// listbox.process_event(Event::Key(Key::Up));
+///
+/// assert_eq!(listbox.focused_idx(), 1);
+/// assert_eq!(listbox.selected_indices(), [0, 2]);
+///
+/// let mut backend = MemBackend::new(9, 3);
+/// backend.render(|buf| listbox.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "[x] a.txt\n[ ] b.txt\n[x] c.txt");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ListBox {
+    pub theme: Theme,
+    items: Vec<String>,
+    selected: Vec<bool>,
+    focused_idx: usize,
+    // HACK: FIXME: this is state related purely to drawing.
+    scroll: Cell<usize>,
+}
+
+impl ListBox {
+    /// Creates a new `ListBox` without any selected items.
+    pub fn new(items: &[&str]) -> Self
+    {
+        Self {
+            theme: Theme::default(),
+            items: items.iter()
+                .map(|it| it.to_string())
+                .collect(),
+            selected: vec![false; items.len()],
+            focused_idx: 0,
+            scroll: Cell::new(0),
+        }
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Gets a reference to the items.
+    #[inline]
+    pub fn items(&self) -> &[String]
+    {
+        &self.items
+    }
+
+    /// Gets the index of the focused item.
+    #[inline]
+    pub fn focused_idx(&self) -> usize
+    {
+        self.focused_idx
+    }
+
+    /// Checks if the item at `idx` is selected.
+    ///
+    /// # Panics
+    ///
+    /// When `idx` is out of bounds.
+    #[inline]
+    pub fn is_selected(&self, idx: usize) -> bool
+    {
+        self.selected[idx]
+    }
+
+    /// Selects or deselects the item at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// When `idx` is out of bounds.
+    #[inline]
+    pub fn set_selected(&mut self, idx: usize, selected: bool)
+    {
+        self.selected[idx] = selected;
+    }
+
+    /// Gets the indices of the selected items, in ascending order.
+    pub fn selected_indices(&self) -> Vec<usize>
+    {
+        self.selected.iter()
+            .enumerate()
+            .filter_map(|(idx, &selected)| selected.then_some(idx))
+            .collect()
+    }
+}
+
+impl<R: Render> Draw<R> for ListBox {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        scroll_to_item(&self.scroll, self.focused_idx, area.height as usize);
+
+        let start = self.scroll.get();
+        let visible = self.items.iter()
+            .zip(&self.selected)
+            .enumerate()
+            .skip(start)
+            .take(area.height as usize);
+
+        for (y, (idx, (item, &selected))) in visible.enumerate() {
+            let marker = if selected
+                { self.theme.checked }
+                else { self.theme.unchecked };
+
+            let mut line = StyledString::from(marker) + item;
+            if idx == self.focused_idx {
+                line.style = self.theme.focused;
+            }
+
+            buf.print(Pos { x: 0, y: y as u16 }, &line, area);
+        }
+    }
+}
+
+impl Proportional for ListBox {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions::flexible()
+    }
+}
+
+impl InteractiveWidget for ListBox {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        match e {
+            Event::Key(Key::Up) if self.focused_idx > 0 => {
+                self.focused_idx -= 1;
+            },
+            Event::Key(Key::Down) if self.focused_idx + 1 < self.items.len() => {
+                self.focused_idx += 1;
+            },
+            Event::Key(Key::Home) if self.focused_idx > 0 => {
+                self.focused_idx = 0;
+            },
+            Event::Key(Key::End) if self.focused_idx + 1 < self.items.len() => {
+                self.focused_idx = self.items.len() - 1;
+            },
+            Event::Key(Key::Char(' ')) if !self.items.is_empty() => {
+                let selected = &mut self.selected[self.focused_idx];
+                *selected = !*selected;
+            },
+            _ => return false,
+        }
+
+        true
+    }
+}
@@ -0,0 +1,142 @@
+use crate::alloc::string::StyledString;
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::render::{Render, Draw};
+use crate::style::{StyledStr, WithStyle};
+use crate::util::{char_width, str_width};
+use crate::Pos;
+
+
+/// Text scrolling horizontally through the paint area.
+///
+/// Text that does not fit into the paint area is drawn starting at column
+/// `offset`, followed by `gap` blank columns and the text again, so that it
+/// scrolls continuously. Text that fits is drawn as is and does not scroll.
+///
+/// Since drawing does not modify the widget, the text is advanced explicitly
+/// with [`Marquee::tick`].
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::Marquee;
+///
+/// let mut marquee = Marquee::new("abcdef").gap(2);
+/// let mut backend = MemBackend::new(4, 1);
+///
+/// backend.render(|buf| marquee.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "abcd");
+///
+/// for _ in 0..5 {
+///     marquee.tick();
+/// }
+/// backend.render(|buf| marquee.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "f  a");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Marquee {
+    pub text: StyledString,
+    pub gap: u16,
+    pub offset: usize,
+}
+
+impl Marquee {
+    /// Creates a new `Marquee` with a gap of `3` columns.
+    pub fn new<T: Into<StyledString>>(text: T) -> Self
+    {
+        Self {
+            text: text.into(),
+            gap: 3,
+            offset: 0,
+        }
+    }
+
+    /// Adjusts the number of blank columns between the end of the text and
+    /// its next repetition.
+    #[inline]
+    pub fn gap(mut self, gap: u16) -> Self
+    {
+        self.gap = gap;
+
+        self
+    }
+
+    /// Advances the text by one column.
+    #[inline]
+    pub fn tick(&mut self)
+    {
+        self.offset = (self.offset + 1)
+            .checked_rem(self.cycle_width())
+            .unwrap_or(0);
+    }
+
+    /// Width of the text together with the gap.
+    #[inline]
+    fn cycle_width(&self) -> usize
+    {
+        str_width(&self.text.content) + self.gap as usize
+    }
+}
+
+impl<R: Render> Draw<R> for Marquee {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let text = StyledStr::from(&self.text);
+        let width = area.width as usize;
+
+        let blank = ' '.with_style(|_| text.style);
+        buf.hfill(area.top_left(), blank, width);
+
+        if str_width(text.content) <= width {
+            buf.print(Pos::ZERO, text, area);
+            return;
+        }
+
+        let cycle = self.cycle_width();
+        let mut start = -((self.offset % cycle) as isize);
+
+        while start < width as isize {
+            let mut x = start;
+
+            for (idx, c) in text.content.char_indices() {
+                let w = char_width(c) as isize;
+
+                if w == 0 {
+                    continue;
+                }
+                // Characters cut off by the edges are left out.
+                if x >= 0 && x + w <= width as isize {
+                    let pos = Pos { x: x as u16, y: 0 };
+
+                    if c.is_control() {
+                        buf.putc(pos, blank, area);
+                    } else {
+                        let c = text.slice(idx..idx + c.len_utf8());
+                        buf.print(pos, c, area);
+                    }
+                }
+
+                x += w;
+            }
+
+            start += cycle as isize;
+        }
+    }
+}
+
+impl Proportional for Marquee {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::fixed(1),
+        }
+    }
+}
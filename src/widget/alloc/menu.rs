@@ -3,10 +3,10 @@ use std::cell::Cell;
 use crate::layout::{Proportional, Proportions};
 use crate::Pos;
 use crate::alloc::string::StyledString;
-use crate::Dim;
 use crate::render::{Render, Draw};
 use super::InteractiveWidget;
-use termion::event::{Event, Key};
+use crate::widget::mouse_pos;
+use termion::event::{Event, Key, MouseButton, MouseEvent};
 
 use crate::Area;
 
@@ -24,16 +24,8 @@ impl Theme {
     pub const fn default() -> Self
     {
         Self {
-            normal: |item| {
-                let mut line = StyledString::from("  ");
-                line.content.push_str(item);
-                line
-            },
-            selected: |item| {
-                let mut line = StyledString::from("* ");
-                line.content.push_str(item);
-                line
-            },
+            normal: |item| StyledString::from("  ") + item,
+            selected: |item| StyledString::from("* ") + item,
         }
     }
 }
@@ -45,13 +37,83 @@ impl Default for Theme {
     }
 }
 
-enum Location {
-    Above,
-    InView,
-    Below,
-}
-
 /// Simple themable menu-like widget.
+///
+/// The selection is moved with the arrow keys, Home and End, and by the
+/// height of the last drawn area with Page Up and Page Down.
+///
+/// Clicking an item selects it, and the mouse wheel scrolls the list by one
+/// item. The selection is moved only when it would leave the view. The click
+/// positions are mapped to the items using the last drawn area, which assumes
+/// that the paint area starts at the top left of the terminal.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key, MouseButton, MouseEvent};
+/// use cwinui::widget::{InteractiveWidget, Menu, Canvas};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::layout::Dim;
+///
+/// let items: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+/// let items: Vec<&str> = items.iter().map(|it| it.as_str()).collect();
+/// let mut menu = Menu::new(&items);
+///
+/// let mut canvas = Canvas::new(Dim { width: 5, height: 3 });
+/// let mut buf = canvas.renderer();
+/// let area = buf.area();
+/// menu.draw(&mut buf, area);
+///
+/// menu.process_event(Event::Key(Key::PageDown));
+/// assert_eq!(menu.selected_idx(), 3);
+/// menu.process_event(Event::Key(Key::End));
+/// assert_eq!(menu.selected_idx(), 9);
+/// menu.process_event(Event::Key(Key::PageUp));
+/// assert_eq!(menu.selected_idx(), 6);
+/// menu.process_event(Event::Key(Key::Home));
+/// assert_eq!(menu.selected_idx(), 0);
+///
+/// // Terminal coordinates start at 1.
+/// let click = MouseEvent::Press(MouseButton::Left, 1, 3);
+/// menu.process_event(Event::Mouse(click));
+/// assert_eq!(menu.selected_idx(), 2);
+/// let click = MouseEvent::Press(MouseButton::Left, 1, 4);
+/// menu.process_event(Event::Mouse(click));
+/// assert_eq!(menu.selected_idx(), 2);
+/// ```
+///
+/// When the selection moves below the view, the view is scrolled so that the
+/// selected item is on the last row:
+///
+/// ```
+/// use termion::event::{Event, Key, MouseButton, MouseEvent};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::widget::{InteractiveWidget, Menu};
+/// use cwinui::render::{Draw, Render};
+///
+/// let items: Vec<String> = (0..20).map(|i| i.to_string()).collect();
+/// let items: Vec<&str> = items.iter().map(|it| it.as_str()).collect();
+/// let mut menu = Menu::new(&items);
+/// let mut backend = MemBackend::new(4, 5);
+///
+/// for _ in 0..10 {
+///     menu.process_event(Event::Key(Key::Down));
+/// }
+/// backend.render(|buf| menu.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "  6 \n  7 \n  8 \n  9 \n* 10");
+///
+/// // The wheel scrolls the view, dragging the selection along.
+/// let wheel_down = MouseEvent::Press(MouseButton::WheelDown, 1, 1);
+/// for _ in 0..3 {
+///     menu.process_event(Event::Mouse(wheel_down));
+/// }
+/// backend.render(|buf| menu.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "  9 \n* 10\n  11\n  12\n  13");
+/// ```
 #[derive(Debug, Clone)]
 pub struct Menu {
     pub theme: Theme,
@@ -59,6 +121,8 @@ pub struct Menu {
     active_idx: usize,
     // HACK: FIXME: this is state related purely to drawing.
     scroll: Cell<usize>,
+    // HACK: FIXME: this is state related purely to drawing.
+    area: Cell<Area>,
 }
 
 impl Menu {
@@ -71,6 +135,7 @@ impl Menu {
                 .collect(),
             active_idx: 0,
             scroll: Cell::new(0),
+            area: Cell::new(Area::default()),
             theme: Theme::default(),
         }
     }
@@ -89,6 +154,18 @@ impl Menu {
         self.active_idx
     }
 
+    /// Selects the item at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// When `idx` is out of bounds.
+    #[inline]
+    pub fn select(&mut self, idx: usize)
+    {
+        assert!(idx < self.items.len(), "index out of bounds");
+        self.active_idx = idx;
+    }
+
     /// Adjusts the theme.
     #[inline]
     pub fn theme(mut self, theme: Theme) -> Self
@@ -105,22 +182,47 @@ impl Menu {
         &self.items
     }
 
+    /// Gets the number of items to move by with Page Up and Page Down.
+    ///
+    /// This is the height of the last drawn area, but at least `1`.
     #[inline]
-    fn visible_count(&self, height: u16) -> u16
+    fn page_size(&self) -> usize
     {
-        std::cmp::min(height as usize, self.items.len()) as u16
+        std::cmp::max(self.area.get().height as usize, 1)
     }
 
-    #[inline]
-    fn active_item_location(&self, dimensions: Dim) -> Location
+    /// Gets the index of the item drawn at `pos`, if any.
+    fn item_at(&self, pos: Pos) -> Option<usize>
     {
-        if self.active_idx < self.scroll.get() {
-            Location::Above
-        } else if self.active_idx < self.scroll.get() + dimensions.height as usize {
-            Location::InView
-        } else {
-            Location::Below
+        let area = self.area.get();
+        if !area.contains_pos(pos) {
+            return None;
         }
+
+        let idx = self.scroll.get() + (pos.y - area.y) as usize;
+
+        (idx < self.items.len()).then_some(idx)
+    }
+
+    #[inline]
+    fn visible_count(&self, height: u16) -> u16
+    {
+        std::cmp::min(height as usize, self.items.len()) as u16
+    }
+}
+
+/// Adjusts `scroll`, the index of the first visible item, so that the item at
+/// `idx` is visible in a list of `height` rows.
+///
+/// When the item is above the view, it becomes the first visible item. When
+/// it is below the view, it becomes the last one.
+#[inline]
+pub(crate) fn scroll_to_item(scroll: &Cell<usize>, idx: usize, height: usize)
+{
+    if idx < scroll.get() {
+        scroll.set(idx);
+    } else if idx >= scroll.get() + height {
+        scroll.set((idx + 1).saturating_sub(height));
     }
 }
 
@@ -131,12 +233,9 @@ impl<R: Render> Draw<R> for Menu {
             return;
         }
 
-        match self.active_item_location(area.dimensions()) {
-            Location::Above => self.scroll.set(self.active_idx),
-            Location::InView => {},
-            Location::Below => self.scroll.set(self.active_idx
-                .saturating_sub(area.height as usize + 1)),
-        }
+        self.area.set(area);
+
+        scroll_to_item(&self.scroll, self.active_idx, area.height as usize);
 
         let start = self.scroll.get();
         let end = self.scroll.get() + self.visible_count(area.height) as usize;
@@ -162,20 +261,62 @@ impl Proportional for Menu {
 
 impl InteractiveWidget for Menu {
     fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
     {
         match e {
-            Event::Key(Key::Up) => {
-                if self.active_idx > 0 {
-                    self.active_idx -= 1;
-                }
+            Event::Key(Key::Up) if self.active_idx > 0 => {
+                self.active_idx -= 1;
+            },
+            Event::Key(Key::Down) if self.active_idx + 1 < self.items.len() => {
+                self.active_idx += 1;
             },
-            Event::Key(Key::Down) => {
-                if self.active_idx + 1 < self.items.len() {
-                    self.active_idx += 1;
-                }
+            Event::Key(Key::Home) if self.active_idx > 0 => self.active_idx = 0,
+            Event::Key(Key::End) if self.active_idx + 1 < self.items.len() => {
+                self.active_idx = self.items.len() - 1;
             },
-            // TODO: mouse support
-            _ => (),
+            Event::Key(Key::PageUp) if self.active_idx > 0 => {
+                self.active_idx = self.active_idx
+                    .saturating_sub(self.page_size());
+            },
+            Event::Key(Key::PageDown)
+                if self.active_idx + 1 < self.items.len() =>
+            {
+                self.active_idx = std::cmp::min(
+                    self.active_idx + self.page_size(),
+                    self.items.len() - 1
+                );
+            },
+            Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y)) => {
+                let Some(idx) = self.item_at(mouse_pos(x, y)) else {
+                    return false;
+                };
+
+                self.active_idx = idx;
+            },
+            Event::Mouse(MouseEvent::Press(MouseButton::WheelUp, _, _))
+                if self.scroll.get() > 0 =>
+            {
+                let scroll = self.scroll.get() - 1;
+                let last_visible = scroll + self.page_size() - 1;
+
+                self.scroll.set(scroll);
+                self.active_idx = std::cmp::min(self.active_idx, last_visible);
+            },
+            Event::Mouse(MouseEvent::Press(MouseButton::WheelDown, _, _))
+                if self.scroll.get() + self.page_size() < self.items.len() =>
+            {
+                let scroll = self.scroll.get() + 1;
+
+                self.scroll.set(scroll);
+                self.active_idx = std::cmp::max(self.active_idx, scroll);
+            },
+            _ => return false,
         }
+
+        true
     }
 }
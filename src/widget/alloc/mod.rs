@@ -1,11 +1,43 @@
 use crate::widget::InteractiveWidget;
 
 pub mod inputline;
+pub mod numberinput;
 pub mod menu;
+pub mod listbox;
+pub mod combobox;
 pub mod prompt;
+pub mod gauge;
+pub mod checkbox;
+pub mod radio;
+pub mod tabs;
+pub mod table;
+pub mod tree;
+pub mod paragraph;
+pub mod scrollview;
+pub mod focus;
+pub mod marquee;
+pub mod spans;
+pub mod textarea;
+pub mod autocomplete;
 mod canvas;
 
 pub use inputline::InputLine;
+pub use numberinput::NumberInput;
 pub use menu::Menu;
+pub use listbox::ListBox;
+pub use combobox::ComboBox;
 pub use prompt::Prompt;
 pub use canvas::Canvas;
+pub use gauge::Gauge;
+pub use checkbox::Checkbox;
+pub use radio::RadioGroup;
+pub use tabs::Tabs;
+pub use table::Table;
+pub use tree::{Tree, TreeNode};
+pub use paragraph::Paragraph;
+pub use scrollview::ScrollView;
+pub use focus::FocusGroup;
+pub use marquee::Marquee;
+pub use spans::Spans;
+pub use textarea::TextArea;
+pub use autocomplete::Autocomplete;
@@ -0,0 +1,256 @@
+use termion::event::{Event, Key};
+
+use super::{InputLine, InteractiveWidget};
+use crate::layout::{Area, Proportional, Proportions};
+use crate::render::{Render, Draw};
+
+
+/// Input field for numbers, built on top of [`InputLine`].
+///
+/// Only digits are accepted, optionally with a leading `-` (see
+/// `allow_negative`) and a single `.` (see `allow_decimal`). Up and Down
+/// increment and decrement the value by `step`.
+///
+/// The value is clamped to `min` and `max` when it is read and when it is
+/// stepped. The input itself is not clamped while typing, so that e.g. `15`
+/// can be typed when the minimum is `10`.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::widget::{InteractiveWidget, NumberInput};
+///
+/// let mut input = NumberInput::new().step(0.5).max(Some(2.0));
+/// assert_eq!(input.value(), None);
+///
+/// for key in [Key::Char('1'), Key::Char('x'), Key::Char('.'), Key::Char('2')] {
+///     input.process_event(Event::Key(key));
+/// }
+/// assert_eq!(input.input.content(), "1.2");
+///
+/// input.process_event(Event::Key(Key::Up));
+/// assert_eq!(input.input.content(), "1.7");
+/// input.process_event(Event::Key(Key::Up));
+/// assert_eq!(input.value(), Some(2.0));
+///
+/// input.process_event(Event::Key(Key::Char('-')));
+/// assert_eq!(input.value(), Some(2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct NumberInput {
+    pub input: InputLine,
+    pub step: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub allow_negative: bool,
+    pub allow_decimal: bool,
+}
+
+impl NumberInput {
+    /// Creates a new, empty `NumberInput` for decimal numbers, with a step of
+    /// `1` and no bounds.
+    pub const fn new() -> Self
+    {
+        Self {
+            input: InputLine::new(),
+            step: 1.0,
+            min: None,
+            max: None,
+            allow_negative: true,
+            allow_decimal: true,
+        }
+    }
+
+    /// Adjusts the amount to increment or decrement by.
+    #[inline]
+    pub const fn step(mut self, step: f64) -> Self
+    {
+        self.step = step;
+
+        self
+    }
+
+    /// Adjusts the lower bound.
+    #[inline]
+    pub const fn min(mut self, min: Option<f64>) -> Self
+    {
+        self.min = min;
+
+        self
+    }
+
+    /// Adjusts the upper bound.
+    #[inline]
+    pub const fn max(mut self, max: Option<f64>) -> Self
+    {
+        self.max = max;
+
+        self
+    }
+
+    /// Adjusts the option to accept a leading `-`.
+    ///
+    /// Without it, stepping stops at `0`.
+    ///
+    /// ```
+    /// use termion::event::{Event, Key};
+    /// use cwinui::widget::{InteractiveWidget, NumberInput};
+    ///
+    /// let mut input = NumberInput::new().allow_negative(false);
+    /// input.process_event(Event::Key(Key::Down));
+    ///
+    /// assert_eq!(input.input.content(), "0");
+    /// assert_eq!(input.value(), Some(0.0));
+    /// ```
+    #[inline]
+    pub const fn allow_negative(mut self, flag: bool) -> Self
+    {
+        self.allow_negative = flag;
+
+        self
+    }
+
+    /// Adjusts the option to accept a decimal point.
+    #[inline]
+    pub const fn allow_decimal(mut self, flag: bool) -> Self
+    {
+        self.allow_decimal = flag;
+
+        self
+    }
+
+    /// Gets the value of the input, clamped to the bounds.
+    ///
+    /// Returns `None` when the input is empty or not a complete number (e.g.
+    /// just `-`).
+    pub fn value(&self) -> Option<f64>
+    {
+        let value: f64 = self.input.content().parse().ok()?;
+
+        Some(self.clamp(value))
+    }
+
+    /// Clamps `value` to the bounds, and to `0` if negative numbers are not
+    /// allowed.
+    fn clamp(&self, mut value: f64) -> f64
+    {
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        if !self.allow_negative {
+            value = value.max(0.0);
+        }
+
+        value
+    }
+
+    /// Adds `delta` to the value, treating an empty input as `0`.
+    fn step_by(&mut self, delta: f64)
+    {
+        let value = self.clamp(self.value().unwrap_or(0.0) + delta);
+
+        // Keep as many decimals as the step or the input have, to avoid
+        // printing rounding errors.
+        let decimals = if self.allow_decimal {
+            std::cmp::max(
+                decimals(&self.step.to_string()),
+                decimals(self.input.content()),
+            )
+        } else {
+            0
+        };
+
+        self.input.set_content(&format!("{value:.decimals$}"));
+    }
+
+    /// Checks if `s` is a number or a prefix of one.
+    fn is_partial_number(&self, s: &str) -> bool
+    {
+        let s = match s.strip_prefix('-') {
+            Some(rest) if self.allow_negative => rest,
+            Some(_) => return false,
+            None => s,
+        };
+
+        let mut points = 0;
+
+        s.chars().all(|c| match c {
+            '0'..='9' => true,
+            '.' if self.allow_decimal => {
+                points += 1;
+                points == 1
+            },
+            _ => false,
+        })
+    }
+}
+
+impl Default for NumberInput {
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/// Number of digits after the decimal point in `s`.
+fn decimals(s: &str) -> usize
+{
+    s.split_once('.')
+        .map(|(_, frac)| frac.len())
+        .unwrap_or(0)
+}
+
+impl<R: Render> Draw<R> for NumberInput {
+    #[inline]
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        self.input.draw(buf, area);
+    }
+}
+
+impl Proportional for NumberInput {
+    #[inline]
+    fn proportions(&self) -> Proportions
+    {
+        self.input.proportions()
+    }
+}
+
+impl InteractiveWidget for NumberInput {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        match e {
+            Event::Key(Key::Up) => {
+                self.step_by(self.step);
+                true
+            },
+            Event::Key(Key::Down) => {
+                self.step_by(-self.step);
+                true
+            },
+            // Deleting characters cannot turn a partial number into something
+            // else, so only insertions are checked.
+            Event::Key(Key::Char(c)) => {
+                let content = self.input.content();
+                let offset = content.char_indices()
+                    .nth(self.input.cursor())
+                    .map_or(content.len(), |(offset, _)| offset);
+
+                let mut candidate = content.to_string();
+                candidate.insert(offset, c);
+
+                self.is_partial_number(&candidate) && self.input.handle_event(e)
+            },
+            e => self.input.handle_event(e),
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::alloc::string::StyledString;
+use crate::layout::{Area, Justify, Proportional, Proportions, Range};
+use crate::render::{Render, Draw};
+use crate::style::StyledStr;
+use crate::util::{char_width, str_width};
+use crate::widget::text::print_rtl;
+
+
+/// Horizontal alignment of the lines of a [`Paragraph`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Word-wrapped block of text.
+///
+/// Newlines in the text are hard line breaks. Words that do not fit on a line
+/// on their own are broken up.
+///
+/// `scroll` determines the first wrapped line to be drawn. It is not clamped
+/// when drawing, so scrolling past the end leaves the area empty; use
+/// [`Paragraph::max_scroll`] to clamp it.
+///
+/// With `rtl` set, each line is drawn right-to-left (see
+/// [`RtlLine`](crate::widget::text::RtlLine) for the limitations) and the
+/// alignment is mirrored, i.e. [`Align::Left`] anchors the lines at the right
+/// edge.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::Paragraph;
+///
+/// let paragraph = Paragraph::new("abc de").rtl(true);
+///
+/// let mut backend = MemBackend::new(5, 2);
+/// backend.render(|buf| paragraph.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "  cba\n   ed");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Paragraph {
+    pub text: StyledString,
+    pub align: Align,
+    pub scroll: u16,
+    pub rtl: bool,
+}
+
+impl Paragraph {
+    /// Creates a new left-aligned `Paragraph`.
+    pub fn new<T: Into<StyledString>>(text: T) -> Self
+    {
+        Self {
+            text: text.into(),
+            align: Align::Left,
+            scroll: 0,
+            rtl: false,
+        }
+    }
+
+    /// Adjusts the alignment.
+    #[inline]
+    pub fn align(mut self, align: Align) -> Self
+    {
+        self.align = align;
+
+        self
+    }
+
+    /// Adjusts the scroll offset.
+    #[inline]
+    pub fn scroll(mut self, scroll: u16) -> Self
+    {
+        self.scroll = scroll;
+
+        self
+    }
+
+    /// Scrolls to wrapped line `y`.
+    #[inline]
+    pub fn scroll_to(&mut self, y: u16)
+    {
+        self.scroll = y;
+    }
+
+    /// Scrolls by `delta` wrapped lines, up if negative, down if positive.
+    #[inline]
+    pub fn scroll_by(&mut self, delta: i32)
+    {
+        let y = (self.scroll as i32 + delta).clamp(0, u16::MAX as i32);
+        self.scroll_to(y as u16);
+    }
+
+    /// Gets the highest scroll offset that still shows some text, when drawn
+    /// in an area that is `width` columns wide.
+    ///
+    /// This is the offset of the last wrapped line.
+    ///
+    /// ```
+    /// use cwinui::widget::Paragraph;
+    ///
+    /// let mut paragraph = Paragraph::new("one two three\nfour");
+    ///
+    /// assert_eq!(paragraph.max_scroll(5), 3);
+    /// assert_eq!(paragraph.max_scroll(20), 1);
+    ///
+    /// paragraph.scroll_by(10);
+    /// let max = paragraph.max_scroll(5);
+    /// paragraph.scroll_to(std::cmp::min(paragraph.scroll, max));
+    /// assert_eq!(paragraph.scroll, 3);
+    ///
+    /// paragraph.scroll_by(-5);
+    /// assert_eq!(paragraph.scroll, 0);
+    /// ```
+    pub fn max_scroll(&self, width: u16) -> u16
+    {
+        let lines = wrap(&self.text.content, width as usize).len();
+
+        std::cmp::min(lines.saturating_sub(1), u16::MAX as usize) as u16
+    }
+
+    /// Adjusts the option to draw the lines right-to-left.
+    #[inline]
+    pub fn rtl(mut self, rtl: bool) -> Self
+    {
+        self.rtl = rtl;
+
+        self
+    }
+}
+
+impl<R: Render> Draw<R> for Paragraph {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let lines = wrap(&self.text.content, area.width as usize);
+        let visible = lines.iter()
+            .skip(self.scroll as usize)
+            .take(area.height as usize);
+
+        for (y, &line) in visible.enumerate() {
+            let y = y as u16;
+            let line = StyledStr { content: line, style: self.text.style };
+
+            if self.rtl {
+                let free_width = (area.width as usize)
+                    .saturating_sub(str_width(line.content)) as u16;
+                let right = match self.align {
+                    Align::Left   => area.width,
+                    Align::Center => area.width - free_width / 2,
+                    Align::Right  => area.width - free_width,
+                };
+
+                print_rtl(buf, line, right, y, area);
+                continue;
+            }
+
+            let justify = match self.align {
+                Align::Left   => Justify::Left(y),
+                Align::Center => Justify::HCenter(y),
+                Align::Right  => Justify::Right(y),
+            };
+
+            buf.jprint(line, justify, area);
+        }
+    }
+}
+
+impl Proportional for Paragraph {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::from(1),
+            height: Range::from(1),
+        }
+    }
+}
+
+
+/// Word-wraps `text` into lines of at most `width` columns.
+///
+/// Newlines are hard line breaks. Spaces at which the lines are broken are
+/// left out.
+fn wrap(text: &str, width: usize) -> Vec<&str>
+{
+    let mut lines = Vec::new();
+
+    if width == 0 {
+        return lines;
+    }
+
+    for line in text.split('\n') {
+        if str_width(line) <= width {
+            lines.push(line);
+            continue;
+        }
+
+        let mut start = 0;
+        let mut col = 0;
+        let mut last_space = None;
+
+        for (idx, c) in line.char_indices() {
+            let w = char_width(c);
+
+            if col > 0 && col + w > width {
+                if c == ' ' {
+                    lines.push(&line[start..idx]);
+                    start = idx + 1;
+                    col = 0;
+                    last_space = None;
+                    continue;
+                }
+
+                match last_space {
+                    Some(space) => {
+                        lines.push(&line[start..space]);
+                        start = space + 1;
+                        col = str_width(&line[start..idx]);
+                    },
+                    None => {
+                        lines.push(&line[start..idx]);
+                        start = idx;
+                        col = 0;
+                    },
+                }
+
+                last_space = None;
+            }
+
+            if c == ' ' {
+                last_space = Some(idx);
+            }
+
+            col += w;
+        }
+
+        lines.push(&line[start..]);
+    }
+
+    lines
+}
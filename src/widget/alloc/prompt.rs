@@ -37,6 +37,20 @@ struct ThemeInternal {
 }
 
 /// Prompt-like wrapper for [`InputLine`].
+///
+/// # Example
+///
+/// ```
+/// use cwinui::layout::{Proportional, Range};
+/// use cwinui::widget::Prompt;
+///
+/// let prompt = Prompt::new("Name");
+/// let proportions = prompt.proportions();
+///
+/// // "Name", ": " and one column for the input, on a single row.
+/// assert_eq!(proportions.width, Range::from(7));
+/// assert_eq!(proportions.height, Range::fixed(1));
+/// ```
 #[derive(Debug, Clone)]
 pub struct Prompt {
     pub label: StyledString,
@@ -74,6 +88,7 @@ impl Prompt {
         self.inputline.theme = super::inputline::Theme {
             input_style,
             blank_c,
+            ..self.inputline.theme
         };
 
         self
@@ -89,6 +104,7 @@ impl Prompt {
         self.inputline.theme = super::inputline::Theme {
             input_style,
             blank_c,
+            ..self.inputline.theme
         };
     }
 
@@ -152,8 +168,8 @@ impl Proportional for Prompt {
             + 1) as u16;
 
         Proportions {
-            height: Range::from(min),
-            width: Range::fixed(1)
+            width: Range::from(min),
+            height: Range::fixed(1),
         }
     }
 }
@@ -163,4 +179,10 @@ impl InteractiveWidget for Prompt {
     {
         self.inputline.process_event(e);
     }
+
+    #[inline]
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        self.inputline.handle_event(e)
+    }
 }
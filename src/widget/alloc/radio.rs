@@ -0,0 +1,239 @@
+use std::cell::Cell;
+
+use termion::event::{Event, Key};
+
+use super::InteractiveWidget;
+use super::menu::scroll_to_item;
+use crate::Pos;
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::render::{Render, Draw};
+use crate::style::{Style, StyledChar, StyledStr, TextStyle};
+use crate::util::str_width;
+
+
+/// Configuration options for theming [`RadioGroup`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub selected: StyledChar,
+    pub unselected: StyledChar,
+    /// Style of the highlighted item.
+    pub highlight: Style,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    pub const fn default() -> Self
+    {
+        Self {
+            selected: StyledChar { content: '•', style: Style::default() },
+            unselected: StyledChar { content: ' ', style: Style::default() },
+            highlight: Style::default().text_style(TextStyle::INVERT),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Vertical list of items, out of which exactly one is selected.
+///
+/// Up and Down move the highlight, Space and Enter select the highlighted item.
+/// Home and End move the highlight to the first and the last item, and Page Up
+/// and Page Down by the height of the last drawn area. Drawn as `(•) Item`.
+///
+/// The view is scrolled the same way as in [`Menu`](super::Menu).
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{InteractiveWidget, RadioGroup};
+///
+/// let mut radio = RadioGroup::new(&["a", "b", "c", "d"]);
+/// let mut backend = MemBackend::new(5, 2);
+///
+/// radio.process_event(Event::Key(Key::End));
+/// radio.process_event(Event::Key(Key::Char(' ')));
+/// backend.render(|buf| radio.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "( ) c\n(•) d");
+///
+/// // Moving the highlight within the view does not scroll it.
+/// radio.process_event(Event::Key(Key::Up));
+/// backend.render(|buf| radio.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "( ) c\n(•) d");
+///
+/// radio.process_event(Event::Key(Key::PageUp));
+/// assert_eq!(radio.selected(), "d");
+/// backend.render(|buf| radio.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "( ) a\n( ) b");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RadioGroup {
+    pub theme: Theme,
+    items: Vec<String>,
+    selected: usize,
+    highlighted: usize,
+    // HACK: FIXME: this is state related purely to drawing.
+    scroll: Cell<usize>,
+    // HACK: FIXME: this is state related purely to drawing.
+    height: Cell<u16>,
+}
+
+impl RadioGroup {
+    /// Creates a new `RadioGroup` with the first item selected.
+    pub fn new(items: &[&str]) -> Self
+    {
+        Self {
+            items: items.iter()
+                .map(|it| it.to_string())
+                .collect(),
+            selected: 0,
+            highlighted: 0,
+            theme: Theme::default(),
+            scroll: Cell::new(0),
+            height: Cell::new(0),
+        }
+    }
+
+    /// Gets a reference to the currently selected item.
+    #[inline]
+    pub fn selected(&self) -> &str
+    {
+        &self.items[self.selected]
+    }
+
+    /// Gets the index of the currently selected item.
+    #[inline]
+    pub fn selected_idx(&self) -> usize
+    {
+        self.selected
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Gets a reference to the items.
+    #[inline]
+    pub fn items(&self) -> &[String]
+    {
+        &self.items
+    }
+
+    /// Gets the number of items to move by with Page Up and Page Down.
+    ///
+    /// This is the height of the last drawn area, but at least `1`.
+    #[inline]
+    fn page_size(&self) -> usize
+    {
+        std::cmp::max(self.height.get() as usize, 1)
+    }
+}
+
+impl<R: Render> Draw<R> for RadioGroup {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        self.height.set(area.height);
+
+        let height = area.height as usize;
+        scroll_to_item(&self.scroll, self.highlighted, height);
+
+        let start = std::cmp::min(self.scroll.get(), self.items.len());
+        let end = std::cmp::min(start + height, self.items.len());
+
+        for (i, item) in self.items[start..end].iter().enumerate() {
+            let item_i = start + i;
+            let y = i as u16;
+
+            let marker = if self.selected == item_i
+                { self.theme.selected }
+                else { self.theme.unselected };
+            let style = if self.highlighted == item_i
+                { self.theme.highlight }
+                else { Style::default() };
+
+            buf.print(Pos { x: 0, y }, "(", area);
+            buf.putc(Pos { x: 1, y }, marker, area);
+            buf.print(Pos { x: 2, y }, ") ", area);
+            buf.print(Pos { x: 4, y }, StyledStr { content: item, style }, area);
+        }
+    }
+}
+
+impl Proportional for RadioGroup {
+    fn proportions(&self) -> Proportions
+    {
+        let width = self.items.iter()
+            .map(|it| str_width(it))
+            .max()
+            .unwrap_or(0)
+            + 4;
+        let height = self.items.len();
+
+        Proportions {
+            width: Range::from(std::cmp::min(width, u16::MAX as usize) as u16),
+            height: Range::from(std::cmp::min(height, u16::MAX as usize) as u16),
+        }
+    }
+}
+
+impl InteractiveWidget for RadioGroup {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        match e {
+            Event::Key(Key::Up) if self.highlighted > 0 => {
+                self.highlighted -= 1;
+            },
+            Event::Key(Key::Down) if self.highlighted + 1 < self.items.len() => {
+                self.highlighted += 1;
+            },
+            Event::Key(Key::Home) if self.highlighted > 0 => {
+                self.highlighted = 0;
+            },
+            Event::Key(Key::End) if self.highlighted + 1 < self.items.len() => {
+                self.highlighted = self.items.len() - 1;
+            },
+            Event::Key(Key::PageUp) if self.highlighted > 0 => {
+                self.highlighted = self.highlighted
+                    .saturating_sub(self.page_size());
+            },
+            Event::Key(Key::PageDown)
+                if self.highlighted + 1 < self.items.len() =>
+            {
+                self.highlighted = std::cmp::min(
+                    self.highlighted + self.page_size(),
+                    self.items.len() - 1
+                );
+            },
+            Event::Key(Key::Char(' ' | '\n')) => {
+                self.selected = self.highlighted;
+            },
+            _ => return false,
+        }
+
+        true
+    }
+}
@@ -0,0 +1,145 @@
+use std::cell::Cell;
+
+use crate::Pos;
+use crate::layout::{Area, Dim, Proportional, Proportions, Range};
+use crate::buffer::Buffer;
+use crate::render::{Render, Draw};
+
+use super::Canvas;
+
+
+/// Vertically scrollable view of a widget taller than the paint area.
+///
+/// The inner widget is drawn onto an offscreen [`Canvas`] as tall as its
+/// preferred height (its maximum height, or its minimum height when it has no
+/// maximum, but at least the height of the paint area). A window of the canvas
+/// starting at row `scroll` is then copied into the paint area.
+///
+/// The scroll offset is clamped when drawing, so that the view never scrolls
+/// past the end of the content.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::layout::{Dim, Pos};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{Canvas, ScrollView};
+///
+/// let mut content = Canvas::new(Dim { width: 1, height: 5 });
+/// {
+///     let mut content_buf = content.renderer();
+///     for (y, c) in ('0'..='4').enumerate() {
+///         content_buf.set_char(Pos { x: 0, y: y as u16 }, c);
+///     }
+/// }
+///
+/// let mut view = ScrollView::new(content);
+/// let mut backend = MemBackend::new(1, 2);
+///
+/// // The content height is not known before the first draw, so the offset is
+/// // only clamped when drawing.
+/// view.scroll_to(100);
+/// assert_eq!(view.scroll, 100);
+///
+/// backend.render(|buf| view.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "3\n4");
+///
+/// // Afterwards, scrolling is clamped to the last drawn content.
+/// view.scroll_to(100);
+/// assert_eq!(view.scroll, 3);
+///
+/// view.scroll_by(-1);
+/// backend.render(|buf| view.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "2\n3");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScrollView<T> {
+    pub inner: T,
+    /// First row of the content shown in the view.
+    ///
+    /// It can exceed the content height before the first draw, or after the
+    /// content shrinks; the drawn offset is clamped regardless.
+    pub scroll: u16,
+    // HACK: FIXME: this is state related purely to drawing.
+    max_scroll: Cell<u16>,
+}
+
+impl<T> ScrollView<T> {
+    /// Creates a new `ScrollView`, scrolled to the top.
+    pub fn new(inner: T) -> Self
+    {
+        Self {
+            inner,
+            scroll: 0,
+            max_scroll: Cell::new(u16::MAX),
+        }
+    }
+
+    /// Scrolls to row `y` of the content.
+    ///
+    /// The offset is clamped to the content height computed during the last
+    /// draw. Before the first draw, it is not clamped at all.
+    #[inline]
+    pub fn scroll_to(&mut self, y: u16)
+    {
+        self.scroll = std::cmp::min(y, self.max_scroll.get());
+    }
+
+    /// Scrolls by `delta` rows, up if negative, down if positive.
+    ///
+    /// The offset is clamped to the content height computed during the last
+    /// draw.
+    #[inline]
+    pub fn scroll_by(&mut self, delta: i32)
+    {
+        let scroll = std::cmp::min(self.scroll, self.max_scroll.get());
+        let y = (scroll as i32 + delta).clamp(0, u16::MAX as i32);
+        self.scroll_to(y as u16);
+    }
+}
+
+impl<T, R: Render> Draw<R> for ScrollView<T>
+where
+    T: for<'b> Draw<Buffer<'b>> + Proportional,
+{
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let height = self.inner.proportions().height;
+        let content_height = match height.max() {
+            Some(max) => std::cmp::max(max, height.min()),
+            None => std::cmp::max(height.min(), area.height),
+        };
+
+        let max_scroll = content_height.saturating_sub(area.height);
+        self.max_scroll.set(max_scroll);
+
+        let mut canvas = Canvas::new(Dim {
+            width: area.width,
+            height: content_height,
+        });
+        let mut canvas_buf = canvas.buffer();
+        let canvas_area = canvas_buf.area();
+        self.inner.draw(&mut canvas_buf, canvas_area);
+
+        let scroll = std::cmp::min(self.scroll, max_scroll);
+        canvas.draw_from(buf, area, Pos { x: 0, y: scroll });
+    }
+}
+
+impl<T: Proportional> Proportional for ScrollView<T> {
+    /// Same width as the inner widget and fully flexible height.
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: self.inner.proportions().width,
+            height: Range::flexible(),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::layout::{Area, Proportional, Proportions};
+use crate::render::{Render, Draw};
+use crate::style::StyledStr;
+use crate::widget::text::Chain;
+
+
+/// Differently styled segments of text on one line.
+///
+/// The owned counterpart of [`Chain`]. Does not wrap.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::layout::Proportional;
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::style::{Color, WithStyle};
+/// use cwinui::widget::Spans;
+///
+/// let mut spans = Spans::new();
+/// spans.push("OK".with_style(|s| s.fg(Color::Green)));
+/// spans.push(" 42 files".into());
+///
+/// assert_eq!(spans.proportions().width.min(), 11);
+///
+/// let mut backend = MemBackend::new(12, 1);
+/// backend.render(|buf| spans.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "OK 42 files ");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Spans<'s>(pub Vec<StyledStr<'s>>);
+
+impl<'s> Spans<'s> {
+    /// Creates a new `Spans` without any segments.
+    #[inline]
+    pub const fn new() -> Self
+    {
+        Self(Vec::new())
+    }
+
+    /// Appends a segment.
+    #[inline]
+    pub fn push(&mut self, span: StyledStr<'s>)
+    {
+        self.0.push(span);
+    }
+}
+
+impl<'s> From<Vec<StyledStr<'s>>> for Spans<'s> {
+    #[inline]
+    fn from(spans: Vec<StyledStr<'s>>) -> Self
+    {
+        Self(spans)
+    }
+}
+
+impl<R: Render> Draw<R> for Spans<'_> {
+    #[inline]
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        Chain(&self.0).draw(buf, area);
+    }
+}
+
+impl Proportional for Spans<'_> {
+    /// The sum of the display widths of the segments, and a height of `1`.
+    #[inline]
+    fn proportions(&self) -> Proportions
+    {
+        Chain(&self.0).proportions()
+    }
+}
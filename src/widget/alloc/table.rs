@@ -0,0 +1,199 @@
+use crate::Pos;
+use crate::alloc::string::StyledString;
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::render::{Render, Draw};
+use crate::style::{Style, StyledChar, StyledStr, TextStyle};
+use crate::widget::flex::lay_out;
+
+
+/// Configuration options for theming [`Table`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    /// Style merged into the header cells.
+    pub header: Style,
+    /// Fills the row separating the header from the rows.
+    pub sep: StyledChar,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    pub const fn default() -> Self
+    {
+        Self {
+            header: Style::default().text_style(TextStyle::BOLD),
+            sep: StyledChar { content: '─', style: Style::default() },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Table of cells, with a header row.
+///
+/// The columns are laid out left-to-right, according to their width
+/// constraints, in the same way as the items of a
+/// [`FlexRow`](crate::widget::FlexRow). When the paint area cannot fit the
+/// minimum widths, the columns are truncated in order. Each cell is clipped to
+/// its column.
+///
+/// The header is separated from the rows by a separator row.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::alloc::string::StyledString;
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::layout::Range;
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::Table;
+///
+/// let row = |cells: &[&str]| -> Vec<StyledString> {
+///     cells.iter().map(|&c| StyledString::from(c)).collect()
+/// };
+///
+/// // The minimum widths of the columns do not fit, so the second column is
+/// // truncated and the third one is not drawn at all.
+/// let table = Table::new(
+///     row(&["id", "name", "age"]),
+///     vec![row(&["1", "alice", "30"]), row(&["2", "bob", "41"])],
+/// ).widths(&[Range::fixed(4); 3]);
+///
+/// let mut backend = MemBackend::new(6, 4);
+/// backend.render(|buf| table.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "id  na\n──────\n1   al\n2   bo");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub theme: Theme,
+    header: Vec<StyledString>,
+    rows: Vec<Vec<StyledString>>,
+    widths: Vec<Range>,
+}
+
+impl Table {
+    /// Creates a new `Table` with fully flexible columns.
+    ///
+    /// The number of columns is determined by the `header`.
+    pub fn new(header: Vec<StyledString>, rows: Vec<Vec<StyledString>>)
+        -> Self
+    {
+        Self {
+            widths: vec![Range::flexible(); header.len()],
+            header,
+            rows,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Adjusts the width constraints of the columns.
+    ///
+    /// Columns without a constraint are fully flexible.
+    pub fn widths(mut self, widths: &[Range]) -> Self
+    {
+        for (i, width) in self.widths.iter_mut().enumerate() {
+            *width = widths.get(i)
+                .copied()
+                .unwrap_or(Range::flexible());
+        }
+
+        self
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Gets a reference to the header.
+    #[inline]
+    pub fn header(&self) -> &[StyledString]
+    {
+        &self.header
+    }
+
+    /// Gets a reference to the rows.
+    #[inline]
+    pub fn rows(&self) -> &[Vec<StyledString>]
+    {
+        &self.rows
+    }
+}
+
+impl<R: Render> Draw<R> for Table {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() || self.header.is_empty() {
+            return;
+        }
+
+        let mut columns = Vec::with_capacity(self.widths.len());
+        lay_out(&self.widths, area.width, 0, |&w| w, |_, offset, width| {
+            columns.push((offset, width));
+        });
+
+        let cell_area = |col: usize, y: u16| {
+            let (offset, width) = columns[col];
+
+            Area {
+                x: area.x + offset,
+                y: area.y + y,
+                width,
+                height: 1,
+            }
+        };
+
+        // Header
+
+        for (i, cell) in self.header.iter().enumerate() {
+            let cell = StyledStr {
+                content: &cell.content,
+                style: cell.style.merge(self.theme.header),
+            };
+            buf.print(Pos::ZERO, cell, cell_area(i, 0));
+        }
+
+        if area.height < 2 {
+            return;
+        }
+
+        let sep_pos = area.top_left().add_y(1);
+        buf.hfill(sep_pos, self.theme.sep, area.width as usize);
+
+        // Rows
+
+        let visible_rows = (area.height - 2) as usize;
+
+        for (y, row) in self.rows.iter().take(visible_rows).enumerate() {
+            for (i, cell) in row.iter().take(columns.len()).enumerate() {
+                buf.print(Pos::ZERO, cell, cell_area(i, y as u16 + 2));
+            }
+        }
+    }
+}
+
+impl Proportional for Table {
+    /// The width is the sum of the column width constraints. The height fits
+    /// the header, the separator and all the rows.
+    fn proportions(&self) -> Proportions
+    {
+        let width = self.widths.iter()
+            .fold(Range::ZERO, |acc, &w| acc.add(w));
+        let height = std::cmp::min(self.rows.len() + 2, u16::MAX as usize);
+
+        Proportions {
+            width,
+            height: Range::fixed(height as u16),
+        }
+    }
+}
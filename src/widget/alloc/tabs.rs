@@ -0,0 +1,247 @@
+use std::cell::Cell;
+
+use termion::event::{Event, Key, MouseButton, MouseEvent};
+
+use super::InteractiveWidget;
+use crate::Pos;
+use crate::alloc::string::StyledString;
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::render::{Render, Draw};
+use crate::style::{Style, StyledStr, TextStyle};
+use crate::util::str_width;
+use crate::widget::mouse_pos;
+
+
+/// Configuration options for theming [`Tabs`].
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub sep: StyledString,
+    pub normal: Style,
+    pub active: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self {
+            sep: StyledString::from(" | "),
+            normal: Style::default(),
+            active: Style::default().text_style(TextStyle::INVERT),
+        }
+    }
+}
+
+/// Header strip of tab titles.
+///
+/// `Tabs` only draws the header in the first row of the paint area. The page
+/// of the active tab can be drawn in the rest of the area, see
+/// [`Tabs::page_area`].
+///
+/// Left and Right switch to the neighbouring tab, and a click on a title
+/// activates its tab. The titles that do not fit are clipped.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key, MouseButton, MouseEvent};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{InteractiveWidget, Tabs};
+/// use cwinui::widget::text::Line;
+///
+/// let mut tabs = Tabs::new(&["one", "two", "three"]);
+/// let mut backend = MemBackend::new(14, 2);
+///
+/// backend.render(|buf| {
+///     let area = buf.area();
+///     tabs.draw(buf, area);
+///     Line("page one").draw(buf, Tabs::page_area(area));
+/// });
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "one | two | th\npage one      ");
+///
+/// // Terminal coordinates start at 1.
+/// tabs.process_event(Event::Mouse(MouseEvent::Press(MouseButton::Left, 7, 1)));
+/// assert_eq!(tabs.active(), 1);
+///
+/// assert!(tabs.handle_event(Event::Key(Key::Right)));
+/// assert!(!tabs.handle_event(Event::Key(Key::Right)));
+/// assert_eq!(tabs.active(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tabs {
+    pub theme: Theme,
+    titles: Vec<String>,
+    active: usize,
+    // HACK: FIXME: this is state related purely to drawing.
+    area: Cell<Area>,
+}
+
+impl Tabs {
+    /// Creates new `Tabs` with the first tab active.
+    pub fn new(titles: &[&str]) -> Self
+    {
+        Self {
+            titles: titles.iter()
+                .map(|it| it.to_string())
+                .collect(),
+            active: 0,
+            area: Cell::new(Area::default()),
+            theme: Theme::default(),
+        }
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Gets the index of the active tab.
+    #[inline]
+    pub fn active(&self) -> usize
+    {
+        self.active
+    }
+
+    /// Sets the active tab.
+    ///
+    /// Indices out of range select the last tab.
+    #[inline]
+    pub fn set_active(&mut self, idx: usize)
+    {
+        self.active = std::cmp::min(idx, self.titles.len().saturating_sub(1));
+    }
+
+    /// Gets a reference to the titles.
+    #[inline]
+    pub fn titles(&self) -> &[String]
+    {
+        &self.titles
+    }
+
+    /// Splits `area` into the header and the area for the page of the active
+    /// tab.
+    #[inline]
+    pub fn split(area: Area) -> (Area, Area)
+    {
+        area.split_horiz_at(std::cmp::min(1, area.height))
+    }
+
+    /// Computes the area for the page of the active tab, i.e. `area` without
+    /// the header.
+    #[inline]
+    pub fn page_area(area: Area) -> Area
+    {
+        Self::split(area).1
+    }
+
+    /// Finds the tab whose title is drawn at column `x`, relative to the
+    /// header.
+    fn title_at(&self, x: u16) -> Option<usize>
+    {
+        let sep_width = str_width(&self.theme.sep.content);
+        let mut offset = 0;
+
+        for (i, title) in self.titles.iter().enumerate() {
+            let end = offset + str_width(title);
+
+            if (offset..end).contains(&(x as usize)) {
+                return Some(i);
+            }
+
+            offset = end + sep_width;
+        }
+
+        None
+    }
+}
+
+impl<R: Render> Draw<R> for Tabs {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        let (header, _) = Self::split(area);
+        self.area.set(header);
+
+        if header.is_collapsed() {
+            return;
+        }
+
+        let mut offset = 0;
+
+        for (i, title) in self.titles.iter().enumerate() {
+            if offset >= header.width as usize {
+                break;
+            }
+
+            if i > 0 {
+                let pos = Pos { x: offset as u16, y: 0 };
+                buf.print(pos, &self.theme.sep, header);
+                offset += str_width(&self.theme.sep.content);
+
+                if offset >= header.width as usize {
+                    break;
+                }
+            }
+
+            let style = if i == self.active
+                { self.theme.active }
+                else { self.theme.normal };
+
+            buf.print(
+                Pos { x: offset as u16, y: 0 },
+                StyledStr { content: title, style },
+                header
+            );
+            offset += str_width(title);
+        }
+    }
+}
+
+impl Proportional for Tabs {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::from(1),
+        }
+    }
+}
+
+impl InteractiveWidget for Tabs {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        match e {
+            Event::Key(Key::Left) if self.active > 0 => {
+                self.active -= 1;
+            },
+            Event::Key(Key::Right) if self.active + 1 < self.titles.len() => {
+                self.active += 1;
+            },
+            Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y)) => {
+                let header = self.area.get();
+                let pos = mouse_pos(x, y);
+
+                if !header.contains_pos(pos) {
+                    return false;
+                }
+                let Some(idx) = self.title_at(pos.x - header.x) else {
+                    return false;
+                };
+
+                self.active = idx;
+            },
+            _ => return false,
+        }
+
+        true
+    }
+}
@@ -0,0 +1,366 @@
+use std::cell::Cell;
+
+use termion::event::{Event, Key};
+
+use super::InteractiveWidget;
+use crate::Pos;
+use crate::layout::{Area, Proportional, Proportions};
+use crate::render::{Render, Draw};
+use crate::style::{StyledChar, Style, WithStyle};
+use crate::util::{char_width, str_width};
+
+
+/// Configuration options for theming [`TextArea`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub blank_c: StyledChar,
+    pub input_style: Style,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    pub const fn default() -> Self
+    {
+        Self {
+            blank_c: StyledChar { content: ' ', style: Style::default() },
+            input_style: Style::default(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Multi-line text editor.
+///
+/// The cursor is moved with the arrow keys, Home and End. Enter splits the
+/// line at the cursor, Backspace at the start of a line joins it with the
+/// previous one and Delete at the end of a line joins it with the next one.
+///
+/// The view is scrolled both vertically and horizontally to keep the cursor
+/// visible.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{InteractiveWidget, TextArea};
+///
+/// let mut textarea = TextArea::new();
+/// let mut type_in = |textarea: &mut TextArea, s: &str| {
+///     for c in s.chars() {
+///         textarea.process_event(Event::Key(Key::Char(c)));
+///     }
+/// };
+///
+/// type_in(&mut textarea, "one\ntwo\nthree");
+/// assert_eq!(textarea.cursor(), (2, 5));
+///
+/// textarea.process_event(Event::Key(Key::Up));
+/// assert_eq!(textarea.cursor(), (1, 3));
+/// textarea.process_event(Event::Key(Key::Home));
+/// textarea.process_event(Event::Key(Key::Backspace));
+/// assert_eq!(textarea.text(), "onetwo\nthree");
+/// assert_eq!(textarea.cursor(), (0, 3));
+///
+/// type_in(&mut textarea, "\n");
+/// assert_eq!(textarea.text(), "one\ntwo\nthree");
+///
+/// // The view follows the cursor.
+/// let mut backend = MemBackend::new(4, 2);
+/// textarea.process_event(Event::Key(Key::Down));
+/// textarea.process_event(Event::Key(Key::End));
+/// backend.render(|buf| textarea.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// // The cursor is past the end of "three", so it needs a column too.
+/// assert_eq!(backend.to_string(), "o   \nree ");
+/// ```
+///
+/// The view is scrolled by display columns, so wide characters are kept whole:
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{InteractiveWidget, TextArea};
+///
+/// let mut textarea = TextArea::new();
+/// for c in "漢字漢字".chars() {
+///     textarea.process_event(Event::Key(Key::Char(c)));
+/// }
+///
+/// let mut backend = MemBackend::new(6, 1);
+/// backend.render(|buf| textarea.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// // The first visible column would be the second half of "字".
+/// assert_eq!(backend.to_string(), " 漢字 ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextArea {
+    pub theme: Theme,
+    pub active: bool,
+    lines: Vec<String>,
+    row: usize,
+    col: usize,
+    // HACK: FIXME: this is state related purely to drawing.
+    scroll_row: Cell<usize>,
+    // HACK: FIXME: this is state related purely to drawing.
+    scroll_col: Cell<usize>,
+}
+
+impl TextArea {
+    /// Creates a new, empty `TextArea`.
+    pub fn new() -> Self
+    {
+        Self {
+            theme: Theme::default(),
+            active: false,
+            lines: vec![String::new()],
+            row: 0,
+            col: 0,
+            scroll_row: Cell::new(0),
+            scroll_col: Cell::new(0),
+        }
+    }
+
+    /// Gets the text, with the lines joined by `'\n'`.
+    #[inline]
+    pub fn text(&self) -> String
+    {
+        self.lines.join("\n")
+    }
+
+    /// Replaces the text and moves the cursor to the start.
+    pub fn set_text(&mut self, text: &str)
+    {
+        self.lines = text.split('\n')
+            .map(String::from)
+            .collect();
+        self.row = 0;
+        self.col = 0;
+    }
+
+    /// Gets a reference to the lines.
+    #[inline]
+    pub fn lines(&self) -> &[String]
+    {
+        &self.lines
+    }
+
+    /// Gets the position of the cursor as `(row, column)`.
+    ///
+    /// The column is counted in characters.
+    #[inline]
+    pub fn cursor(&self) -> (usize, usize)
+    {
+        (self.row, self.col)
+    }
+
+    /// Adjusts the theme of the `TextArea`.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Gets the number of characters in the line at `row`.
+    #[inline]
+    fn line_len(&self, row: usize) -> usize
+    {
+        self.lines[row].chars().count()
+    }
+
+    /// Gets the byte offset of the cursor in the current line.
+    #[inline]
+    fn cursor_offset(&self) -> usize
+    {
+        byte_offset(&self.lines[self.row], self.col)
+    }
+
+    /// Gets the display column of the cursor in the current line.
+    #[inline]
+    fn cursor_col(&self) -> usize
+    {
+        str_width(&self.lines[self.row][..self.cursor_offset()])
+    }
+
+    /// Scrolls the view of `height` by `width` cells so that the cursor is
+    /// visible, together with the whole character under it.
+    fn scroll_to_cursor(&self, width: usize, height: usize)
+    {
+        if self.row < self.scroll_row.get() {
+            self.scroll_row.set(self.row);
+        } else if self.row >= self.scroll_row.get() + height {
+            self.scroll_row.set(self.row + 1 - height);
+        }
+
+        let col = self.cursor_col();
+        let cursor_width = self.lines[self.row][self.cursor_offset()..]
+            .chars()
+            .next()
+            .map_or(1, |c| std::cmp::max(char_width(c), 1));
+
+        if col < self.scroll_col.get() {
+            self.scroll_col.set(col);
+        } else if col + cursor_width > self.scroll_col.get() + width {
+            self.scroll_col.set((col + cursor_width).saturating_sub(width));
+        }
+    }
+}
+
+impl Default for TextArea {
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/// Gets the byte offset of the character at `idx` in `s`, or the length of `s`
+/// if it is past the end.
+#[inline]
+fn byte_offset(s: &str, idx: usize) -> usize
+{
+    s.char_indices()
+        .nth(idx)
+        .map_or(s.len(), |(offset, _)| offset)
+}
+
+impl<R: Render> Draw<R> for TextArea {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        buf.fill(self.theme.blank_c, area);
+
+        self.scroll_to_cursor(area.width as usize, area.height as usize);
+        let scroll_row = self.scroll_row.get();
+        let scroll_col = self.scroll_col.get();
+
+        let visible = self.lines.iter()
+            .skip(scroll_row)
+            .take(area.height as usize);
+
+        for (y, line) in visible.enumerate() {
+            // Characters cut off by the left edge are left out, so the line
+            // can start a column after the edge.
+            let mut col = 0;
+            let mut start = line.len();
+            for (idx, c) in line.char_indices() {
+                if col >= scroll_col {
+                    start = idx;
+                    break;
+                }
+                col += char_width(c);
+            }
+
+            let x = col.saturating_sub(scroll_col) as u16;
+            let line = line[start..].with_style(|_| self.theme.input_style);
+
+            buf.print(Pos { x, y: y as u16 }, line, area);
+        }
+
+        if self.active {
+            buf.move_cursor(Pos {
+                x: area.x + (self.cursor_col() - scroll_col) as u16,
+                y: area.y + (self.row - scroll_row) as u16,
+            });
+            buf.show_cursor();
+        }
+    }
+}
+
+impl Proportional for TextArea {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions::flexible()
+    }
+}
+
+impl InteractiveWidget for TextArea {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        match e {
+            Event::Key(Key::Char('\n')) => {
+                let offset = self.cursor_offset();
+                let rest = self.lines[self.row].split_off(offset);
+
+                self.row += 1;
+                self.col = 0;
+                self.lines.insert(self.row, rest);
+            },
+            Event::Key(Key::Char(c)) if !c.is_control() => {
+                let offset = self.cursor_offset();
+
+                self.lines[self.row].insert(offset, c);
+                self.col += 1;
+            },
+            Event::Key(Key::Backspace) if self.col > 0 => {
+                self.col -= 1;
+                let offset = self.cursor_offset();
+
+                self.lines[self.row].remove(offset);
+            },
+            Event::Key(Key::Backspace) if self.row > 0 => {
+                let line = self.lines.remove(self.row);
+
+                self.row -= 1;
+                self.col = self.line_len(self.row);
+                self.lines[self.row].push_str(&line);
+            },
+            Event::Key(Key::Delete) if self.col < self.line_len(self.row) => {
+                let offset = self.cursor_offset();
+
+                self.lines[self.row].remove(offset);
+            },
+            Event::Key(Key::Delete) if self.row + 1 < self.lines.len() => {
+                let line = self.lines.remove(self.row + 1);
+
+                self.lines[self.row].push_str(&line);
+            },
+            Event::Key(Key::Left) if self.col > 0 => self.col -= 1,
+            Event::Key(Key::Left) if self.row > 0 => {
+                self.row -= 1;
+                self.col = self.line_len(self.row);
+            },
+            Event::Key(Key::Right) if self.col < self.line_len(self.row) => {
+                self.col += 1;
+            },
+            Event::Key(Key::Right) if self.row + 1 < self.lines.len() => {
+                self.row += 1;
+                self.col = 0;
+            },
+            Event::Key(Key::Up) if self.row > 0 => {
+                self.row -= 1;
+                self.col = std::cmp::min(self.col, self.line_len(self.row));
+            },
+            Event::Key(Key::Down) if self.row + 1 < self.lines.len() => {
+                self.row += 1;
+                self.col = std::cmp::min(self.col, self.line_len(self.row));
+            },
+            Event::Key(Key::Home) if self.col > 0 => self.col = 0,
+            Event::Key(Key::End) if self.col < self.line_len(self.row) => {
+                self.col = self.line_len(self.row);
+            },
+            _ => return false,
+        }
+
+        true
+    }
+}
@@ -0,0 +1,315 @@
+use std::cell::Cell;
+
+use termion::event::{Event, Key};
+
+use super::InteractiveWidget;
+use crate::Pos;
+use crate::layout::{Area, Proportional, Proportions};
+use crate::render::{Render, Draw};
+use crate::style::{Style, StyledChar, StyledStr, TextStyle};
+
+
+/// Configuration options for theming [`Tree`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub expanded: StyledChar,
+    pub collapsed: StyledChar,
+    pub leaf: StyledChar,
+    /// Style of the focused node.
+    pub highlight: Style,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    pub const fn default() -> Self
+    {
+        Self {
+            expanded: StyledChar { content: '▾', style: Style::default() },
+            collapsed: StyledChar { content: '▸', style: Style::default() },
+            leaf: StyledChar { content: ' ', style: Style::default() },
+            highlight: Style::default().text_style(TextStyle::INVERT),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Node of a [`Tree`].
+#[derive(Debug, Clone)]
+pub struct TreeNode {
+    pub label: String,
+    pub children: Vec<TreeNode>,
+    pub expanded: bool,
+}
+
+impl TreeNode {
+    /// Creates a new collapsed `TreeNode` without children.
+    pub fn new<T: Into<String>>(label: T) -> Self
+    {
+        Self {
+            label: label.into(),
+            children: Vec::new(),
+            expanded: false,
+        }
+    }
+
+    /// Adds a child.
+    #[inline]
+    pub fn child(mut self, child: TreeNode) -> Self
+    {
+        self.children.push(child);
+
+        self
+    }
+
+    /// Adjusts the expanded state.
+    #[inline]
+    pub fn expanded(mut self, expanded: bool) -> Self
+    {
+        self.expanded = expanded;
+
+        self
+    }
+}
+
+/// Hierarchical list with collapsible nodes.
+///
+/// Up and Down move the focus, Right expands and Left collapses the focused
+/// node (or moves the focus to its parent), Enter toggles it.
+///
+/// The view is scrolled to keep the focused node visible.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{InteractiveWidget, Tree, TreeNode};
+///
+/// let mut tree = Tree::new(vec![
+///     TreeNode::new("src")
+///         .child(TreeNode::new("main.rs"))
+///         .child(TreeNode::new("lib.rs")),
+///     TreeNode::new("Cargo.toml"),
+/// ]);
+/// let mut backend = MemBackend::new(12, 3);
+///
+/// assert!(tree.handle_event(Event::Key(Key::Right)));
+/// assert!(!tree.handle_event(Event::Key(Key::Right)));
+///
+/// for _ in 0..3 {
+///     tree.process_event(Event::Key(Key::Down));
+/// }
+/// assert_eq!(tree.selected_path(), [1]);
+///
+/// backend.render(|buf| tree.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "    main.rs \n    lib.rs  \n  Cargo.toml");
+///
+/// // Left on a leaf moves the focus to its parent, then collapses it.
+/// tree.process_event(Event::Key(Key::Up));
+/// tree.process_event(Event::Key(Key::Left));
+/// assert_eq!(tree.selected_path(), [0]);
+/// tree.process_event(Event::Key(Key::Left));
+/// assert!(!tree.handle_event(Event::Key(Key::Left)));
+///
+/// backend.render(|buf| tree.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "▸ src       \n  Cargo.toml\n            ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tree {
+    pub theme: Theme,
+    roots: Vec<TreeNode>,
+    /// Index of the focused node among the visible nodes.
+    cursor: usize,
+    // HACK: FIXME: this is state related purely to drawing.
+    scroll: Cell<usize>,
+}
+
+impl Tree {
+    /// Creates a new `Tree`.
+    pub fn new(roots: Vec<TreeNode>) -> Self
+    {
+        Self {
+            roots,
+            cursor: 0,
+            scroll: Cell::new(0),
+            theme: Theme::default(),
+        }
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Gets a reference to the root nodes.
+    #[inline]
+    pub fn roots(&self) -> &[TreeNode]
+    {
+        &self.roots
+    }
+
+    /// Gets the index path of the focused node, starting at the roots.
+    ///
+    /// The path is empty if the tree is empty.
+    pub fn selected_path(&self) -> Vec<usize>
+    {
+        self.visible()
+            .into_iter()
+            .nth(self.cursor)
+            .unwrap_or_default()
+    }
+
+    /// Computes the paths of the visible nodes, in the order they are drawn.
+    fn visible(&self) -> Vec<Vec<usize>>
+    {
+        fn walk(
+            nodes: &[TreeNode],
+            path: &mut Vec<usize>,
+            out: &mut Vec<Vec<usize>>
+        )
+        {
+            for (i, node) in nodes.iter().enumerate() {
+                path.push(i);
+                out.push(path.clone());
+                if node.expanded {
+                    walk(&node.children, path, out);
+                }
+                path.pop();
+            }
+        }
+
+        let mut out = Vec::new();
+        walk(&self.roots, &mut Vec::new(), &mut out);
+
+        out
+    }
+
+    fn node(&self, path: &[usize]) -> &TreeNode
+    {
+        let mut node = &self.roots[path[0]];
+        for &i in &path[1..] {
+            node = &node.children[i];
+        }
+
+        node
+    }
+
+    fn node_mut(&mut self, path: &[usize]) -> &mut TreeNode
+    {
+        let mut node = &mut self.roots[path[0]];
+        for &i in &path[1..] {
+            node = &mut node.children[i];
+        }
+
+        node
+    }
+}
+
+impl<R: Render> Draw<R> for Tree {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let visible = self.visible();
+        let height = area.height as usize;
+
+        if self.cursor < self.scroll.get() {
+            self.scroll.set(self.cursor);
+        } else if self.cursor >= self.scroll.get() + height {
+            self.scroll.set(self.cursor + 1 - height);
+        }
+
+        let start = self.scroll.get();
+        let end = std::cmp::min(start + height, visible.len());
+
+        for (i, path) in visible[start..end].iter().enumerate() {
+            let node = self.node(path);
+            let indent = ((path.len() - 1) * 2) as u16;
+            let y = i as u16;
+
+            let marker = match (node.children.is_empty(), node.expanded) {
+                (true, _)      => self.theme.leaf,
+                (false, true)  => self.theme.expanded,
+                (false, false) => self.theme.collapsed,
+            };
+            let style = if start + i == self.cursor
+                { self.theme.highlight }
+                else { Style::default() };
+
+            buf.putc(Pos { x: indent, y }, marker, area);
+            buf.print(
+                Pos { x: indent + 2, y },
+                StyledStr { content: &node.label, style },
+                area
+            );
+        }
+    }
+}
+
+impl Proportional for Tree {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions::flexible()
+    }
+}
+
+impl InteractiveWidget for Tree {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        let visible = self.visible();
+        let Some(path) = visible.get(self.cursor) else {
+            return false;
+        };
+        let node = self.node(path);
+        let has_children = !node.children.is_empty();
+        let expanded = node.expanded;
+
+        match e {
+            Event::Key(Key::Up) if self.cursor > 0 => {
+                self.cursor -= 1;
+            },
+            Event::Key(Key::Down) if self.cursor + 1 < visible.len() => {
+                self.cursor += 1;
+            },
+            Event::Key(Key::Right) if has_children && !expanded => {
+                self.node_mut(path).expanded = true;
+            },
+            Event::Key(Key::Left) if has_children && expanded => {
+                self.node_mut(path).expanded = false;
+            },
+            Event::Key(Key::Left) if path.len() > 1 => {
+                let parent = &path[..path.len() - 1];
+                self.cursor = visible.iter()
+                    .position(|p| p == parent)
+                    .unwrap_or(self.cursor);
+            },
+            Event::Key(Key::Char('\n')) if has_children => {
+                let node = self.node_mut(path);
+                node.expanded = !node.expanded;
+            },
+            _ => return false,
+        }
+
+        true
+    }
+}
@@ -5,7 +5,8 @@ use super::{Render, Draw};
 
 /// Allows two widgets to be drawn in the same area, on top of each other.
 ///
-/// First, `B` is drawn and then `F` is drawn in the same area.
+/// First, `B` is drawn and then `F` is drawn in the same area. To layer more
+/// than two widgets, see [`Stack`](super::Stack).
 #[derive(Debug, Clone)]
 pub struct Backdrop<F, B> {
     pub fg: F,
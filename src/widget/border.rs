@@ -1,10 +1,43 @@
 use crate::layout::{Proportional, Proportions};
 use crate::style::{Style, StyledChar};
-use crate::{Area, Dim, Pos};
+use crate::{Area, Dim};
 
 use super::{Draw, Render};
 
 /// Configuration options for theming [`Border`].
+///
+/// Besides the default theme, there are presets with the usual box-drawing
+/// characters, e.g. [`Theme::rounded`].
+///
+/// ```
+/// use cwinui::widget::{Border, border::Theme};
+///
+/// let border = Border::new(()).theme(Theme::rounded());
+///
+/// assert_eq!(border.theme.top_left.content, '╭');
+/// assert_eq!(border.theme.right.content, '│');
+/// assert_eq!(Theme::double().bottom_right.content, '╝');
+/// ```
+///
+/// Individual parts can be adjusted with the per-side builders:
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::style::WithStyle;
+/// use cwinui::widget::{Border, Void, border::Theme};
+///
+/// let theme = Theme::rounded()
+///     .top('='.styled())
+///     .corners('+'.styled());
+/// let border = Border::new(Void).theme(theme);
+///
+/// let mut backend = MemBackend::new(4, 3);
+/// backend.render(|buf| border.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "+==+\n│  │\n+──+");
+/// ```
 #[derive(Debug, Clone, Copy)]
 pub struct Theme {
     pub top_left: StyledChar,
@@ -33,6 +66,102 @@ impl Theme {
             left: c,
         }
     }
+
+    /// Theme with light box-drawing lines: `┌┐└┘─│`.
+    pub const fn plain() -> Self
+    {
+        Self::from_chars(['┌', '┐', '┘', '└'], '─', '│')
+    }
+
+    /// Theme with light box-drawing lines and rounded corners: `╭╮╰╯─│`.
+    pub const fn rounded() -> Self
+    {
+        Self::from_chars(['╭', '╮', '╯', '╰'], '─', '│')
+    }
+
+    /// Theme with double box-drawing lines: `╔╗╚╝═║`.
+    pub const fn double() -> Self
+    {
+        Self::from_chars(['╔', '╗', '╝', '╚'], '═', '║')
+    }
+
+    /// Theme with heavy box-drawing lines: `┏┓┗┛━┃`.
+    pub const fn thick() -> Self
+    {
+        Self::from_chars(['┏', '┓', '┛', '┗'], '━', '┃')
+    }
+
+    /// Theme with ASCII characters only: `++++-|`.
+    pub const fn ascii() -> Self
+    {
+        Self::from_chars(['+', '+', '+', '+'], '-', '|')
+    }
+
+    /// Adjusts the *top* side.
+    pub const fn top(mut self,    c: StyledChar) -> Self { self.top    = c; self }
+    /// Adjusts the *right* side.
+    pub const fn right(mut self,  c: StyledChar) -> Self { self.right  = c; self }
+    /// Adjusts the *bottom* side.
+    pub const fn bottom(mut self, c: StyledChar) -> Self { self.bottom = c; self }
+    /// Adjusts the *left* side.
+    pub const fn left(mut self,   c: StyledChar) -> Self { self.left   = c; self }
+
+    /// Adjusts the *top left* corner.
+    pub const fn top_left(mut self,     c: StyledChar) -> Self
+    { self.top_left     = c; self }
+    /// Adjusts the *top right* corner.
+    pub const fn top_right(mut self,    c: StyledChar) -> Self
+    { self.top_right    = c; self }
+    /// Adjusts the *bottom right* corner.
+    pub const fn bottom_right(mut self, c: StyledChar) -> Self
+    { self.bottom_right = c; self }
+    /// Adjusts the *bottom left* corner.
+    pub const fn bottom_left(mut self,  c: StyledChar) -> Self
+    { self.bottom_left  = c; self }
+
+    /// Adjusts all four corners.
+    pub const fn corners(mut self, c: StyledChar) -> Self
+    {
+        self.top_left = c;
+        self.top_right = c;
+        self.bottom_right = c;
+        self.bottom_left = c;
+
+        self
+    }
+
+    /// Adjusts the top and bottom sides to `horiz` and the left and right
+    /// sides to `vert`.
+    pub const fn sides(mut self, horiz: StyledChar, vert: StyledChar) -> Self
+    {
+        self.top = horiz;
+        self.bottom = horiz;
+        self.left = vert;
+        self.right = vert;
+
+        self
+    }
+
+    /// Creates an unstyled theme from `corners` (clockwise, starting at the
+    /// top left), `horiz` for the top and bottom and `vert` for the sides.
+    const fn from_chars(corners: [char; 4], horiz: char, vert: char) -> Self
+    {
+        const fn c(content: char) -> StyledChar
+        {
+            StyledChar { content, style: Style::default() }
+        }
+
+        Self {
+            top_left: c(corners[0]),
+            top_right: c(corners[1]),
+            bottom_right: c(corners[2]),
+            bottom_left: c(corners[3]),
+            top: c(horiz),
+            right: c(vert),
+            bottom: c(horiz),
+            left: c(vert),
+        }
+    }
 }
 
 impl Default for Theme {
@@ -76,39 +205,13 @@ impl<T: Draw<R>, R: Render> Draw<R> for Border<T> {
             return;
         }
 
-        // Sides
-
-        let top_left = area.top_left();
-        let top_right = area.top_right().sub_x(1);
-        let bottom_left = area.bottom_left().sub_y(1);
-        let bottom_right = area.bottom_right() - Pos { x: 1, y: 1 };
-
-        buf.hfill(top_left, self.theme.top, area.width as usize);
-        buf.hfill(bottom_left, self.theme.bottom, area.width as usize);
-        buf.vfill(top_left, self.theme.left, area.height as usize);
-        buf.vfill(top_right, self.theme.right, area.height as usize);
-
-        // Corners
+        // Outline
 
-        buf.putc_abs(top_left, self.theme.top_left);
-        buf.putc_abs(top_right, self.theme.top_right);
-        buf.putc_abs(bottom_left, self.theme.bottom_left);
-        buf.putc_abs(bottom_right, self.theme.bottom_right);
+        buf.rect(area, &self.theme);
 
         // Inner
 
-        let inner_area = if area.width >= 2 && area.height >= 2
-            { area.inset(1) }
-            else {
-                Area {
-                    x: area.x + 1,
-                    y: area.y + 1,
-                    width: 0,
-                    height: 0
-                }
-            };
-
-        self.inner.draw(buf, inner_area);
+        self.inner.draw(buf, area.inset_xy(1, 1));
     }
 }
 
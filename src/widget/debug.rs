@@ -2,7 +2,7 @@ use crate::{Area, Pos};
 use crate::style::WithStyle;
 use crate::layout::{Justify, Proportional, Proportions};
 
-use super::{border, Border, Render, Draw};
+use super::{border, Render, Draw};
 
 
 /// Option flags for [`Wireframe`].
@@ -45,6 +45,40 @@ impl Default for Flags {
 ///
 /// Can show debugging information such as the outline of the paint area and the
 /// size of the paint area.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::Wireframe;
+///
+/// let mut backend = MemBackend::new(5, 5);
+///
+/// let diagonals = Wireframe::new()
+///     .outline(false)
+///     .size(false)
+///     .center(false)
+///     .corners(false)
+///     .midpoints(false);
+/// backend.render(|buf| diagonals.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "     \n \\ / \n  /  \n / \\ \n     ");
+///
+/// let outline = Wireframe::new()
+///     .size(false)
+///     .center(false)
+///     .diagonals(false)
+///     .midpoints(false);
+/// backend.render(|buf| outline.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "+---+\n|   |\n|   |\n|   |\n+---+");
+///
+/// let center = Wireframe::new().outline(false).size(false).midpoints(false);
+/// backend.render(|buf| center.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "+   +\n \\ / \n  x  \n / \\ \n+   +");
+/// ```
 #[derive(Debug, Clone)]
 pub struct Wireframe {
     pub flags: Flags,
@@ -114,6 +148,14 @@ impl Wireframe {
     }
 }
 
+impl Default for Wireframe {
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
 impl<R: Render> Draw<R> for Wireframe {
     fn draw(&self, buf: &mut R, area: crate::Area)
     {
@@ -122,23 +164,10 @@ impl<R: Render> Draw<R> for Wireframe {
         }
 
         let corner = '+'.styled();
-        let vbar = '|'.styled();
-        let hbar = '-'.styled();
         let center = 'x'.styled();
 
         if self.flags.outline {
-            Border::new(super::Void)
-                .theme(border::Theme {
-                    top_left: corner,
-                    top_right: corner,
-                    bottom_right: corner,
-                    bottom_left: corner,
-                    top: hbar,
-                    right: vbar,
-                    bottom: hbar,
-                    left: vbar,
-                })
-                .draw(buf, area);
+            buf.rect(area, &border::Theme::ascii());
         }
 
         if self.flags.midpoints {
@@ -173,7 +202,13 @@ impl<R: Render> Draw<R> for Wireframe {
             buf.jputc(corner, Justify::BottomLeft, area);
             buf.jputc(corner, Justify::BottomRight, area);
         }
-        if self.flags.diagonals {
+        if self.flags.diagonals && area.width > 2 && area.height > 2 {
+            let top_right = Pos { x: area.width - 1, y: 0 };
+            let bottom_left = Pos { x: 0, y: area.height - 1 };
+            let bottom_right = top_right + bottom_left;
+
+            draw_line(buf, area, Pos::ZERO, bottom_right, '\\');
+            draw_line(buf, area, top_right, bottom_left, '/');
         }
         if self.flags.center {
             let width_is_even  = area.width & 1 == 0;
@@ -215,6 +250,48 @@ impl<R: Render> Draw<R> for Wireframe {
     }
 }
 
+/// Draws a line from `from` to `to` (relative to `area`), using Bresenham's
+/// algorithm.
+///
+/// Only the interior of `area` is drawn to, so that the outline is left
+/// intact.
+fn draw_line<R: Render>(buf: &mut R, area: Area, from: Pos, to: Pos, c: char)
+{
+    let (x1, y1) = (to.x as i32, to.y as i32);
+    let (mut x, mut y) = (from.x as i32, from.y as i32);
+
+    let dx = (x1 - x).abs();
+    let dy = -(y1 - y).abs();
+    let step_x = if x < x1 { 1 } else { -1 };
+    let step_y = if y < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    loop {
+        let interior = x > 0
+            && y > 0
+            && x < area.width as i32 - 1
+            && y < area.height as i32 - 1;
+        if interior {
+            buf.putc(Pos { x: x as u16, y: y as u16 }, c, area);
+        }
+
+        if x == x1 && y == y1 {
+            break;
+        }
+
+        let err2 = 2 * err;
+        if err2 >= dy {
+            err += dy;
+            x += step_x;
+        }
+        if err2 <= dx {
+            err += dx;
+            y += step_y;
+        }
+    }
+}
+
+
 impl Proportional for Wireframe {
     #[inline]
     fn proportions(&self) -> Proportions
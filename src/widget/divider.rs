@@ -0,0 +1,128 @@
+use crate::layout::{Area, Justify, Proportional, Proportions, Range};
+use crate::style::{Style, StyledChar, StyledStr};
+use crate::util::str_width;
+
+use super::{Draw, Render};
+
+
+/// Configuration options for theming [`Divider`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub rule: StyledChar,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    #[inline]
+    pub const fn default() -> Self
+    {
+        Self {
+            rule: StyledChar { content: '─', style: Style::default() },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Horizontal rule with an optional centered label.
+///
+/// The label is padded with a space on each side. When the paint area is too
+/// narrow to fit the padded label, only the rule is drawn.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::Divider;
+///
+/// let divider = Divider::new().label("abc");
+///
+/// let mut backend = MemBackend::new(10, 1);
+/// backend.render(|buf| divider.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "── abc ───");
+///
+/// let mut backend = MemBackend::new(4, 1);
+/// backend.render(|buf| divider.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "────");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Divider<'a> {
+    pub label: Option<StyledStr<'a>>,
+    pub theme: Theme,
+}
+
+impl<'a> Divider<'a> {
+    /// Creates a new `Divider` without a label.
+    #[inline]
+    pub const fn new() -> Self
+    {
+        Self {
+            label: None,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Adjusts the label.
+    #[inline]
+    pub fn label<T: Into<StyledStr<'a>>>(mut self, label: T) -> Self
+    {
+        self.label = Some(label.into());
+
+        self
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+}
+
+impl<R: Render> Draw<R> for Divider<'_> {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let top_left = area.top_left();
+        buf.hfill(top_left, self.theme.rule, area.width as usize);
+
+        let Some(label) = self.label else {
+            return;
+        };
+
+        let label_width = str_width(label.content);
+        if label.content.is_empty() || label_width + 2 > area.width as usize {
+            return;
+        }
+
+        // Same offset as the one computed by `jprint`.
+        let x = (area.width - label_width as u16) / 2;
+        let pad = StyledChar { content: ' ', style: self.theme.rule.style };
+        buf.putc_abs(top_left.add_x(x - 1), pad);
+        buf.putc_abs(top_left.add_x(x + label_width as u16), pad);
+        buf.jprint(label, Justify::HCenter(0), area);
+    }
+}
+
+impl Proportional for Divider<'_> {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::fixed(1),
+        }
+    }
+}
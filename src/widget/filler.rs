@@ -1,5 +1,5 @@
 use crate::Area;
-use crate::style::StyledChar;
+use crate::style::{Style, StyledChar};
 use crate::layout::{Proportional, Proportions};
 
 use super::{Draw, Render};
@@ -23,3 +23,57 @@ impl Proportional for Filler {
         Proportions::flexible()
     }
 }
+
+
+/// Merges a [`Style`] into every cell of the area, leaving the characters
+/// intact.
+///
+/// The style is merged with [`Render::restyle`].
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::layout::{Area, Pos};
+/// use cwinui::style::{Color, Style};
+/// use cwinui::widget::StyleFill;
+///
+/// let mut backend = MemBackend::new(4, 1);
+///
+/// backend.render(|buf| {
+///     buf.set_str(Pos::ZERO, "abcd");
+///
+///     let tint = StyleFill(Style::default().bg(Color::Red));
+///     tint.draw(buf, Area { x: 1, y: 0, width: 2, height: 1 });
+///
+///     assert_eq!(buf.get(Pos { x: 0, y: 0 }).unwrap().style.bg_color, None);
+///     let cell = buf.get(Pos { x: 2, y: 0 }).unwrap();
+///     assert_eq!(cell.content, 'c');
+///     assert_eq!(cell.style.bg_color, Some(Color::Red));
+/// });
+/// ```
+#[derive(Debug, Clone)]
+pub struct StyleFill(pub Style);
+
+impl<R: Render> Draw<R> for StyleFill {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if !buf.area().overlaps(area) {
+            return;
+        }
+        let area = buf.area().intersection(area);
+
+        for pos in area.positions() {
+            buf.restyle(pos, self.0);
+        }
+    }
+}
+
+impl Proportional for StyleFill {
+    #[inline]
+    fn proportions(&self) -> Proportions
+    {
+        Proportions::flexible()
+    }
+}
@@ -9,6 +9,53 @@
 //!
 //! Flexible items whose maximum exceeds the paint area or have no maximum are
 //! truncated to the 100% of the paint area.
+//!
+//! An optional gap of blank cells can be inserted between consecutive items.
+//! The gaps are subtracted from the paint area before the flexible space is
+//! distributed. The last item still takes up the rest of the paint area.
+//!
+//! # Example
+//!
+//! ```
+//! use cwinui::widget::{Canvas, FlexRow};
+//! use cwinui::render::{Draw, Render};
+//! use cwinui::layout::Dim;
+//! # #[derive(Default)]
+//! # struct Probe(std::cell::RefCell<Vec<cwinui::layout::Area>>);
+//! # impl Probe {
+//! #     fn new() -> Self { Self::default() }
+//! #     fn take(&self) -> Vec<cwinui::layout::Area> { self.0.take() }
+//! # }
+//! # impl<R: Render> Draw<R> for Probe {
+//! #     fn draw(&self, _buf: &mut R, area: cwinui::layout::Area)
+//! #     {
+//! #         self.0.borrow_mut().push(area);
+//! #     }
+//! # }
+//! # impl cwinui::layout::Proportional for Probe {
+//! #     fn proportions(&self) -> cwinui::layout::Proportions
+//! #     {
+//! #         cwinui::layout::Proportions::flexible()
+//! #     }
+//! # }
+//!
+//! // `Probe` is a flexible widget recording the areas it is drawn in.
+//! let probe = Probe::new();
+//!
+//! let mut canvas = Canvas::new(Dim { width: 11, height: 1 });
+//! let mut renderer = canvas.renderer();
+//! let area = renderer.area();
+//!
+//! FlexRow::new(&[&probe, &probe, &probe])
+//!     .gap(1)
+//!     .draw(&mut renderer, area);
+//!
+//! let areas = probe.take();
+//! let xs: Vec<_> = areas.iter().map(|a| a.x).collect();
+//! let widths: Vec<_> = areas.iter().map(|a| a.width).collect();
+//! assert_eq!(xs, [0, 4, 8]);
+//! assert_eq!(widths, [3, 3, 3]);
+//! ```
 
 
 use super::Draw;
@@ -30,14 +77,39 @@ where
 /// For more information on how the items are drawn, see the [Module-level
 /// documentation](self).
 #[derive(Clone)]
-pub struct FlexCol<'a, R: Render>(pub &'a [&'a dyn FlexItem<R>]);
+pub struct FlexCol<'a, R: Render> {
+    pub items: &'a [&'a dyn FlexItem<R>],
+    /// Number of blank rows between consecutive items.
+    pub gap: u16,
+}
+
+impl<'a, R: Render> FlexCol<'a, R> {
+    /// Creates a new `FlexCol` without gaps between the items.
+    #[inline]
+    pub const fn new(items: &'a [&'a dyn FlexItem<R>]) -> Self
+    {
+        Self {
+            items,
+            gap: 0,
+        }
+    }
+
+    /// Adjusts the gap between consecutive items.
+    #[inline]
+    pub const fn gap(mut self, gap: u16) -> Self
+    {
+        self.gap = gap;
+
+        self
+    }
+}
 
 impl<'a, R: Render> std::fmt::Debug for FlexCol<'a, R> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
     {
         f.write_str("FlexCol ")?;
         f.debug_list()
-            .entries(self.0.iter().map(|_| FlexItemDbg))
+            .entries(self.items.iter().map(|_| FlexItemDbg))
             .finish()
     }
 }
@@ -45,64 +117,29 @@ impl<'a, R: Render> std::fmt::Debug for FlexCol<'a, R> {
 impl<R: Render> Draw<R> for FlexCol<'_, R> {
     fn draw(&self, buf: &mut R, area: Area)
     {
-        if area.is_collapsed() || self.0.is_empty() {
+        if area.is_collapsed() {
             return;
         }
 
-        let mut min   = 0usize;
-        let mut basis = 0usize;
-
-        for &it in self.0 {
-            let p = it .proportions();
-
-            min   += p.height.min() as usize;
-            basis += calc_grow(p.height, area.height) as usize;
-        }
-
-        let flexy_len    = (area.height as usize).saturating_sub(min) as f64;
-        let growth_scale = if basis == 0
-            { 0. }
-            else { f64::min(1., flexy_len / basis as f64) };
-
-        let mut used = 0;
-        let mut remainder = 0f64;
-
-        for &it in &self.0[..self.0.len() - 1] {
-            let p = it.proportions();
-            let growth
-                = calc_grow(p.height, area.height) as f64
-                * growth_scale
-                + remainder;
-            remainder = growth.fract();
-
-            let height = std::cmp::min(
-                p.height.min() + growth.trunc() as u16,
-                area.height - used,
-            );
-
-            it.draw(buf, Area {
+        lay_out(
+            self.items,
+            area.height,
+            self.gap,
+            |it| it.proportions().height,
+            |it, offset, height| it.draw(buf, Area {
                 x: area.x,
-                y: area.y + used,
+                y: area.y + offset,
                 width: area.width,
                 height,
-            });
-
-            used += height;
-        }
-
-        self.0[self.0.len() - 1].draw(buf, Area {
-            x: area.x,
-            y: area.y + used,
-            width: area.width,
-            height: area.height - used,
-        });
+            }),
+        );
     }
 }
 
 impl<R: Render> Proportional for FlexCol<'_, R> {
     fn proportions(&self) -> Proportions
     {
-        self.0.iter()
+        let p = self.items.iter()
             .fold(Proportions::ZERO, |Proportions { width, height }, it|
         {
             let p = it.proportions();
@@ -111,7 +148,12 @@ impl<R: Render> Proportional for FlexCol<'_, R> {
                 width:  width.join(p.width),
                 height: height.add(p.height),
             }
-        })
+        });
+
+        Proportions {
+            height: p.height.add(Range::fixed(total_gap(self.items, self.gap))),
+            ..p
+        }
     }
 }
 
@@ -121,14 +163,39 @@ impl<R: Render> Proportional for FlexCol<'_, R> {
 /// For more information on how the items are drawn, see the [Module-level
 /// documentation](self).
 #[derive(Clone)]
-pub struct FlexRow<'a, R: Render>(pub &'a [&'a dyn FlexItem<R>]);
+pub struct FlexRow<'a, R: Render> {
+    pub items: &'a [&'a dyn FlexItem<R>],
+    /// Number of blank columns between consecutive items.
+    pub gap: u16,
+}
+
+impl<'a, R: Render> FlexRow<'a, R> {
+    /// Creates a new `FlexRow` without gaps between the items.
+    #[inline]
+    pub const fn new(items: &'a [&'a dyn FlexItem<R>]) -> Self
+    {
+        Self {
+            items,
+            gap: 0,
+        }
+    }
+
+    /// Adjusts the gap between consecutive items.
+    #[inline]
+    pub const fn gap(mut self, gap: u16) -> Self
+    {
+        self.gap = gap;
+
+        self
+    }
+}
 
 impl<'a, R: Render> std::fmt::Debug for FlexRow<'a, R> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
     {
         f.write_str("FlexRow ")?;
         f.debug_list()
-            .entries(self.0.iter().map(|_| FlexItemDbg))
+            .entries(self.items.iter().map(|_| FlexItemDbg))
             .finish()
     }
 }
@@ -136,64 +203,29 @@ impl<'a, R: Render> std::fmt::Debug for FlexRow<'a, R> {
 impl<R: Render> Draw<R> for FlexRow<'_, R> {
     fn draw(&self, buf: &mut R, area: Area)
     {
-        if area.is_collapsed() || self.0.is_empty() {
+        if area.is_collapsed() {
             return;
         }
 
-        let mut min   = 0usize;
-        let mut basis = 0usize;
-
-        for &it in self.0 {
-            let p = it .proportions();
-
-            min   += p.width.min() as usize;
-            basis += calc_grow(p.width, area.width) as usize;
-        }
-
-        let flexy_len    = (area.width as usize).saturating_sub(min) as f64;
-        let growth_scale = if basis == 0
-            { 0. }
-            else { f64::min(1., flexy_len / basis as f64) };
-
-        let mut used = 0;
-        let mut remainder = 0f64;
-
-        for &it in &self.0[..self.0.len() - 1] {
-            let p = it.proportions();
-            let growth
-                = calc_grow(p.width, area.width) as f64
-                * growth_scale
-                + remainder;
-            remainder = growth.fract();
-
-            let width = std::cmp::min(
-                p.width.min() + growth.trunc() as u16,
-                area.width - used,
-            );
-
-            it.draw(buf, Area {
-                x: area.x + used,
+        lay_out(
+            self.items,
+            area.width,
+            self.gap,
+            |it| it.proportions().width,
+            |it, offset, width| it.draw(buf, Area {
+                x: area.x + offset,
                 y: area.y,
                 width,
                 height: area.height,
-            });
-
-            used += width;
-        }
-
-        self.0[self.0.len() - 1].draw(buf, Area {
-            x: area.x + used,
-            y: area.y,
-            width: area.width - used,
-            height: area.height,
-        });
+            }),
+        );
     }
 }
 
 impl<R: Render> Proportional for FlexRow<'_, R> {
     fn proportions(&self) -> Proportions
     {
-        self.0.iter()
+        let p = self.items.iter()
             .fold(Proportions::ZERO, |Proportions { width, height }, it|
         {
             let p = it.proportions();
@@ -202,11 +234,87 @@ impl<R: Render> Proportional for FlexRow<'_, R> {
                 width:  width.add(p.width),
                 height: height.join(p.height),
             }
-        })
+        });
+
+        Proportions {
+            width: p.width.add(Range::fixed(total_gap(self.items, self.gap))),
+            ..p
+        }
     }
 }
 
 
+/// Lays out `items` along `len` cells, according to the size requirements
+/// given by `range`, with `gap` cells between consecutive items.
+///
+/// `place` is called with each item, its offset and its size, in order. The
+/// last item takes up the rest of the space. See the [Module-level
+/// documentation](self) for how the space is distributed.
+pub(crate) fn lay_out<T, F, P>(
+    items: &[T],
+    len: u16,
+    gap: u16,
+    range: F,
+    mut place: P
+)
+where
+    F: Fn(&T) -> Range,
+    P: FnMut(&T, u16, u16),
+{
+    if items.is_empty() {
+        return;
+    }
+
+    let mut min   = 0usize;
+    let mut basis = 0usize;
+
+    for it in items {
+        let r = range(it);
+
+        min   += r.min() as usize;
+        basis += calc_grow(r, len) as usize;
+    }
+
+    let gaps = gap as usize * (items.len() - 1);
+
+    let flexy_len    = (len as usize).saturating_sub(min + gaps) as f64;
+    let growth_scale = if basis == 0
+        { 0. }
+        else { f64::min(1., flexy_len / basis as f64) };
+
+    let mut used = 0;
+    let mut remainder = 0f64;
+
+    for it in &items[..items.len() - 1] {
+        let r = range(it);
+        let growth
+            = calc_grow(r, len) as f64
+            * growth_scale
+            + remainder;
+        remainder = growth.fract();
+
+        let size = std::cmp::min(
+            r.min() + growth.trunc() as u16,
+            len - used,
+        );
+
+        place(it, used, size);
+
+        used = std::cmp::min((used + size).saturating_add(gap), len);
+    }
+
+    place(&items[items.len() - 1], used, len - used);
+}
+
+/// Computes the total size of the gaps between `items`.
+#[inline]
+fn total_gap<T>(items: &[T], gap: u16) -> u16
+{
+    let gaps = gap as usize * items.len().saturating_sub(1);
+
+    std::cmp::min(gaps, u16::MAX as usize) as u16
+}
+
 #[inline]
 fn calc_grow(range: Range, max: u16) -> u16
 {
@@ -0,0 +1,140 @@
+use crate::layout::{Area, Pos, Proportional, Proportions, Range};
+use crate::style::{Style, StyledStr, TextStyle};
+use crate::util::str_width;
+
+use super::{Draw, Render};
+
+
+/// Configuration options for theming [`KeyHints`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme<'a> {
+    /// Style of the keys.
+    pub key: Style,
+    /// Style of the descriptions.
+    pub desc: Style,
+    /// Placed between the pairs.
+    pub sep: StyledStr<'a>,
+}
+
+impl Theme<'_> {
+    /// Const version of `Default::default`.
+    #[inline]
+    pub const fn default() -> Self
+    {
+        Self {
+            key: Style::default().text_style(TextStyle::BOLD),
+            desc: Style::default(),
+            sep: StyledStr { content: "  ", style: Style::default() },
+        }
+    }
+}
+
+impl Default for Theme<'_> {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Row of key hints, e.g. `q:quit  ↑↓:move`.
+///
+/// Each hint is a pair of a key and its description. Trailing hints that do not
+/// fit into the paint area are left out.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::KeyHints;
+///
+/// let hints = KeyHints::new(&[("q", "quit"), ("↑↓", "move"), ("?", "help")]);
+///
+/// // The last hint does not fit in whole, so it is left out.
+/// let mut backend = MemBackend::new(16, 1);
+/// backend.render(|buf| hints.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "q:quit  ↑↓:move ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyHints<'a> {
+    pub hints: &'a [(&'a str, &'a str)],
+    pub theme: Theme<'a>,
+}
+
+impl<'a> KeyHints<'a> {
+    /// Creates a new `KeyHints`.
+    #[inline]
+    pub const fn new(hints: &'a [(&'a str, &'a str)]) -> Self
+    {
+        Self {
+            hints,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Adjusts the separator placed between the pairs.
+    #[inline]
+    pub fn sep<T: Into<StyledStr<'a>>>(mut self, sep: T) -> Self
+    {
+        self.theme.sep = sep.into();
+
+        self
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub const fn theme(mut self, theme: Theme<'a>) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+}
+
+impl<R: Render> Draw<R> for KeyHints<'_> {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let sep_width = str_width(self.theme.sep.content);
+        let mut x = 0;
+
+        for (i, &(key, desc)) in self.hints.iter().enumerate() {
+            let key_width = str_width(key);
+            let hint_width = key_width + 1 + str_width(desc);
+            let lead = if i == 0 { 0 } else { sep_width };
+
+            if x + lead + hint_width > area.width as usize {
+                break;
+            }
+
+            if i > 0 {
+                buf.print(Pos { x: x as u16, y: 0 }, self.theme.sep, area);
+                x += sep_width;
+            }
+
+            let key = StyledStr { content: key, style: self.theme.key };
+            let colon = StyledStr { content: ":", style: self.theme.desc };
+            let desc = StyledStr { content: desc, style: self.theme.desc };
+
+            buf.print(Pos { x: x as u16, y: 0 }, key, area);
+            buf.print(Pos { x: (x + key_width) as u16, y: 0 }, colon, area);
+            buf.print(Pos { x: (x + key_width + 1) as u16, y: 0 }, desc, area);
+
+            x += hint_width;
+        }
+    }
+}
+
+impl Proportional for KeyHints<'_> {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::fixed(1),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::Draw;
-use crate::{Area, Pos};
+use crate::{Area, Dim, Pos};
 use crate::layout::{Alignment, Proportional, Proportions, Range};
 use crate::widget::Render;
 
@@ -29,6 +29,116 @@ impl<T: Proportional> Proportional for Min<T> {
 }
 
 
+/// Draws the wrapped widget in an area no larger than `max`.
+///
+/// The area is anchored to the top left corner of the paint area.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::widget::{Canvas, layout::MaxSize};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::layout::{Area, Dim, Proportional, Range};
+/// # #[derive(Default)]
+/// # struct Probe(std::cell::RefCell<Vec<cwinui::layout::Area>>);
+/// # impl Probe {
+/// #     fn new() -> Self { Self::default() }
+/// #     fn take(&self) -> Vec<cwinui::layout::Area> { self.0.take() }
+/// # }
+/// # impl<R: Render> Draw<R> for Probe {
+/// #     fn draw(&self, _buf: &mut R, area: cwinui::layout::Area)
+/// #     {
+/// #         self.0.borrow_mut().push(area);
+/// #     }
+/// # }
+/// # impl cwinui::layout::Proportional for Probe {
+/// #     fn proportions(&self) -> cwinui::layout::Proportions
+/// #     {
+/// #         cwinui::layout::Proportions::flexible()
+/// #     }
+/// # }
+///
+/// // `Probe` is a flexible widget recording the areas it is drawn in.
+/// let max = MaxSize::new(Probe::new(), Dim { width: 30, height: 10 });
+///
+/// let mut canvas = Canvas::new(Dim { width: 200, height: 60 });
+/// let mut renderer = canvas.renderer();
+/// let area = Area { x: 5, y: 5, width: 195, height: 55 };
+/// max.draw(&mut renderer, area);
+///
+/// assert_eq!(max.inner.take(), [Area { x: 5, y: 5, width: 30, height: 10 }]);
+/// assert_eq!(max.proportions().width, Range::to(30));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MaxSize<T> {
+    pub inner: T,
+    pub max: Dim,
+}
+
+impl<T> MaxSize<T> {
+    /// Wraps `inner`, limiting its size to `max`.
+    pub const fn new(inner: T, max: Dim) -> Self
+    {
+        Self {
+            inner,
+            max,
+        }
+    }
+
+    /// Adjusts the maximum width.
+    pub const fn width(mut self, val: u16) -> Self
+    {
+        self.max.width = val;
+
+        self
+    }
+
+    /// Adjusts the maximum height.
+    pub const fn height(mut self, val: u16) -> Self
+    {
+        self.max.height = val;
+
+        self
+    }
+}
+
+impl<T: Draw<R>, R: Render> Draw<R> for MaxSize<T> {
+    #[inline]
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        let dim = Dim {
+            width: std::cmp::min(area.width, self.max.width),
+            height: std::cmp::min(area.height, self.max.height),
+        };
+
+        self.inner.draw(buf, Area::from_parts(area.top_left(), dim));
+    }
+}
+
+impl<T: Proportional> Proportional for MaxSize<T> {
+    /// The proportions of the inner widget, capped to `max`.
+    fn proportions(&self) -> Proportions
+    {
+        let p = self.inner.proportions();
+
+        Proportions {
+            width: cap(p.width, self.max.width),
+            height: cap(p.height, self.max.height),
+        }
+    }
+}
+
+/// Caps both ends of `range` to `max`.
+#[inline]
+fn cap(range: Range, max: u16) -> Range
+{
+    Range::new(
+        std::cmp::min(range.min(), max),
+        range.max().map_or(max, |m| std::cmp::min(m, max)),
+    )
+}
+
+
 /// Align the contained widget based on `alignment`.
 #[derive(Debug, Clone)]
 pub struct Align<T: Proportional> {
@@ -131,6 +241,116 @@ def_static_align!(BottomCenter);
 def_static_align!(BottomRight);
 
 
+/// Draws the contained widget in the largest sub-area matching the
+/// `width:height` ratio.
+///
+/// The sub-area is aligned within the paint area based on `alignment`. Note
+/// that the ratio is in cells, which are usually not square.
+///
+/// Nothing is drawn when either part of the ratio is `0` or when the sub-area
+/// would be smaller than 1x1.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::style::StyledChar;
+/// use cwinui::widget::Filler;
+/// use cwinui::widget::layout::AspectRatio;
+///
+/// let bg = Filler(StyledChar::from('.'));
+/// let fg = Filler(StyledChar::from('#'));
+/// let mut backend = MemBackend::new(6, 2);
+///
+/// let mut draw = |ratio: &AspectRatio<&Filler>| {
+///     backend.render(|buf| {
+///         bg.draw(buf, buf.area());
+///         ratio.draw(buf, buf.area());
+///     });
+///     backend.flush().unwrap();
+///     backend.to_string()
+/// };
+///
+/// assert_eq!(draw(&AspectRatio::new(&fg, 2, 1)), ".####.\n.####.");
+///
+/// // Degenerate ratios, and sub-areas narrower than one cell, draw nothing.
+/// assert_eq!(draw(&AspectRatio::new(&fg, 0, 1)), "......\n......");
+/// assert_eq!(draw(&AspectRatio::new(&fg, 1, 10)), "......\n......");
+/// ```
+#[derive(Debug, Clone)]
+pub struct AspectRatio<T> {
+    pub inner:     T,
+    pub width:     u16,
+    pub height:    u16,
+    pub alignment: Alignment,
+}
+
+impl<T> AspectRatio<T> {
+    /// Creates a centered `AspectRatio` with the ratio of `width:height`.
+    pub const fn new(inner: T, width: u16, height: u16) -> Self
+    {
+        Self {
+            inner,
+            width,
+            height,
+            alignment: Alignment::Center,
+        }
+    }
+
+    /// Adjusts the alignment.
+    pub const fn align(mut self, alignment: Alignment) -> Self
+    {
+        self.alignment = alignment;
+
+        self
+    }
+
+    /// Computes the largest dimensions within `dim` matching the ratio.
+    fn fit(&self, dim: Dim) -> Dim
+    {
+        let (w, h) = (self.width as u32, self.height as u32);
+
+        let height = dim.width as u32 * h / w;
+        if height <= dim.height as u32 {
+            return Dim { width: dim.width, height: height as u16 };
+        }
+
+        Dim {
+            width: (dim.height as u32 * w / h) as u16,
+            height: dim.height,
+        }
+    }
+}
+
+impl<T: Draw<R>, R: Render> Draw<R> for AspectRatio<T> {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if self.width == 0 || self.height == 0 || area.is_collapsed() {
+            return;
+        }
+
+        let dim = self.fit(area.dimensions());
+        if dim.is_collapsed() {
+            return;
+        }
+
+        let inner_area = Area::from_parts(Pos::ZERO, dim)
+            .align_to(area, self.alignment);
+
+        self.inner.draw(buf, inner_area);
+    }
+}
+
+impl<T> Proportional for AspectRatio<T> {
+    #[inline]
+    fn proportions(&self) -> Proportions
+    {
+        Proportions::flexible()
+    }
+}
+
+
 /// Adds padding to the contained widget.
 #[derive(Debug, Clone)]
 pub struct Pad<T> {
@@ -168,22 +388,17 @@ impl<T: Draw<R>, R: Render> Draw<R> for Pad<T> {
     #[inline]
     fn draw(&self, buf: &mut R, area: Area)
     {
-        let horiz_pad = self.left + self.right;
-        let vert_pad  = self.top  + self.bottom;
-
-        if area.width.saturating_sub(horiz_pad) == 0
-            || area.height.saturating_sub(vert_pad) == 0
-        {
+        let area = area.inset_sides(
+            self.top,
+            self.right,
+            self.bottom,
+            self.left
+        );
+
+        if area.is_collapsed() {
             return;
         }
 
-        let area = Area {
-            x:      area.x + self.left,
-            y:      area.y + self.top,
-            width:  area.width  - horiz_pad,
-            height: area.height - vert_pad,
-        };
-
         self.inner.draw(buf, area);
     }
 }
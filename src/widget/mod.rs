@@ -1,6 +1,6 @@
 use termion::event::Event;
 
-use crate::{Area, Draw, Render};
+use crate::{Area, Draw, Pos, Render};
 
 
 pub mod bar;
@@ -9,17 +9,33 @@ pub mod layout;
 pub mod flex;
 pub mod split;
 pub mod text;
+pub mod progress;
+pub mod scrollbar;
+pub mod slider;
+pub mod divider;
+pub mod keyhints;
+mod sparkline;
 mod filler;
 mod backdrop;
+mod stack;
+mod popup;
 mod debug;
 mod alloc;
 
-pub use split::{Row, Col};
+pub use split::{Row, Col, WeightedRow, WeightedCol};
 pub use flex::{FlexCol, FlexRow};
 pub use bar::{HorizBar, VertBar};
+pub use progress::ProgressBar;
+pub use scrollbar::{HorizScrollbar, VertScrollbar};
+pub use sparkline::Sparkline;
+pub use slider::Slider;
+pub use divider::Divider;
+pub use keyhints::KeyHints;
 pub use border::Border;
-pub use filler::Filler;
+pub use filler::{Filler, StyleFill};
 pub use backdrop::Backdrop;
+pub use stack::Stack;
+pub use popup::Popup;
 pub use debug::Wireframe;
 pub use alloc::*;
 
@@ -28,6 +44,32 @@ pub use alloc::*;
 pub trait InteractiveWidget {
     /// Processes an event.
     fn process_event(&mut self, e: Event);
+
+    /// Processes an event and reports whether it was consumed.
+    ///
+    /// Containers such as [`FocusGroup`] use this to find out if an event
+    /// still needs to be handled. By default, the event is passed to
+    /// [`InteractiveWidget::process_event`] and reported as consumed. The
+    /// widgets in this crate only report the events that they act on, e.g. a
+    /// [`Menu`] does not consume Up when the first item is selected.
+    #[inline]
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        self.process_event(e);
+
+        true
+    }
+}
+
+
+/// Converts the 1-based coordinates of termion's mouse events to a [`Pos`].
+#[inline]
+pub(crate) const fn mouse_pos(x: u16, y: u16) -> Pos
+{
+    Pos {
+        x: x.saturating_sub(1),
+        y: y.saturating_sub(1),
+    }
 }
 
 
@@ -41,6 +83,12 @@ impl<R: Render> Draw<R> for Void {
 
 impl InteractiveWidget for Void {
     fn process_event(&mut self, _e: Event) {}
+
+    #[inline]
+    fn handle_event(&mut self, _e: Event) -> bool
+    {
+        false
+    }
 }
 
 impl crate::layout::Proportional for Void {
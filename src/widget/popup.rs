@@ -0,0 +1,112 @@
+use crate::layout::{Alignment, Area, Dim, Pos, Proportional, Proportions};
+
+use super::{Draw, Render};
+
+
+/// Draws the contained widget floating over whatever was drawn before it.
+///
+/// The popup area is computed from the proportions of `inner` (or from
+/// `size`, when set), fitted into the paint area and aligned within it
+/// according to `alignment`. Unlike [`Backdrop`](super::Backdrop), the cells
+/// outside of the popup area are left untouched.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::layout::{Alignment, Dim};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::style::StyledChar;
+/// use cwinui::widget::{Filler, Popup};
+/// use cwinui::widget::text::Line;
+///
+/// let bg = Filler(StyledChar::from('.'));
+/// let mut backend = MemBackend::new(6, 3);
+///
+/// backend.render(|buf| {
+///     bg.draw(buf, buf.area());
+///     Popup::new(Line("ab")).draw(buf, buf.area());
+/// });
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "......\n..ab..\n......");
+///
+/// // An explicit size overrides the proportions of the inner widget.
+/// let popup = Popup::new(Filler(StyledChar::from('#')))
+///     .size(Dim { width: 3, height: 2 })
+///     .align(Alignment::BottomRight);
+///
+/// backend.render(|buf| {
+///     bg.draw(buf, buf.area());
+///     popup.draw(buf, buf.area());
+/// });
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "......\n...###\n...###");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Popup<T> {
+    pub inner: T,
+    pub size: Option<Dim>,
+    pub alignment: Alignment,
+}
+
+impl<T> Popup<T> {
+    /// Creates a new centered `Popup` sized by the proportions of `inner`.
+    #[inline]
+    pub const fn new(inner: T) -> Self
+    {
+        Self {
+            inner,
+            size: None,
+            alignment: Alignment::Center,
+        }
+    }
+
+    /// Adjusts the size of the popup, overriding the proportions of `inner`.
+    ///
+    /// The size is still clipped to the paint area.
+    #[inline]
+    pub const fn size(mut self, size: Dim) -> Self
+    {
+        self.size = Some(size);
+
+        self
+    }
+
+    /// Adjusts the alignment of the popup within the paint area.
+    #[inline]
+    pub const fn align(mut self, alignment: Alignment) -> Self
+    {
+        self.alignment = alignment;
+
+        self
+    }
+}
+
+impl<T: Draw<R> + Proportional, R: Render> Draw<R> for Popup<T> {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        let proportions = match self.size {
+            Some(size) => Proportions::fixed(size),
+            None => self.inner.proportions(),
+        };
+        let dim = area.dimensions()
+            .fit_into(proportions)
+            .unwrap_or_else(|d| d);
+
+        let inner_area = Area::from_parts(Pos::ZERO, dim)
+            .align_to(area, self.alignment);
+
+        self.inner.draw(buf, inner_area);
+    }
+}
+
+impl<T: Proportional> Proportional for Popup<T> {
+    #[inline]
+    fn proportions(&self) -> Proportions
+    {
+        match self.size {
+            Some(size) => Proportions::fixed(size),
+            None => self.inner.proportions(),
+        }.expand()
+    }
+}
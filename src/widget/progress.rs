@@ -0,0 +1,105 @@
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::style::{Style, StyledChar};
+
+use super::{Draw, Render};
+
+
+/// Configuration options for theming [`ProgressBar`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub filled: StyledChar,
+    pub empty: StyledChar,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    #[inline]
+    pub const fn default() -> Self
+    {
+        Self {
+            filled: StyledChar { content: '#', style: Style::default() },
+            empty: StyledChar { content: ' ', style: Style::default() },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Horizontal bar showing progress, spanning the full width of the paint area.
+///
+/// `ratio` is expected to be within `0.0..=1.0`, values outside of the range
+/// are clamped.
+#[derive(Debug, Clone, Default)]
+pub struct ProgressBar {
+    pub ratio: f32,
+    pub theme: Theme,
+}
+
+impl ProgressBar {
+    /// Creates a new `ProgressBar`.
+    #[inline]
+    pub const fn new(ratio: f32) -> Self
+    {
+        Self {
+            ratio,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Adjusts the theme of the `ProgressBar`.
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+}
+
+impl<R: Render> Draw<R> for ProgressBar {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let filled = filled_width(self.ratio, area.width);
+        let top_left = area.top_left();
+
+        buf.hfill(top_left, self.theme.filled, filled as usize);
+        buf.hfill(
+            top_left.add_x(filled),
+            self.theme.empty,
+            (area.width - filled) as usize
+        );
+    }
+}
+
+impl Proportional for ProgressBar {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::fixed(1),
+        }
+    }
+}
+
+
+/// Number of filled cells in a bar of `width` cells.
+///
+/// `ratio` is clamped to `0.0..=1.0`.
+#[inline]
+pub(crate) fn filled_width(ratio: f32, width: u16) -> u16
+{
+    let ratio = if ratio.is_nan()
+        { 0. }
+        else { ratio.clamp(0., 1.) };
+
+    (ratio * width as f32).round() as u16
+}
@@ -0,0 +1,257 @@
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::style::{Style, StyledChar};
+
+use super::{Draw, Render};
+
+
+/// Configuration options for theming [`VertScrollbar`] and [`HorizScrollbar`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub track: StyledChar,
+    pub thumb: StyledChar,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    ///
+    /// Default theme for [`VertScrollbar`].
+    #[inline]
+    pub const fn default() -> Self
+    {
+        Self {
+            track: StyledChar { content: '│', style: Style::default() },
+            thumb: StyledChar { content: '█', style: Style::default() },
+        }
+    }
+
+    /// Default theme for [`HorizScrollbar`].
+    #[inline]
+    pub const fn horiz() -> Self
+    {
+        Self {
+            track: StyledChar { content: '─', style: Style::default() },
+            thumb: StyledChar { content: '█', style: Style::default() },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Draws a vertical scrollbar starting at the top-left corner of the paint area
+/// and spanning the full height of the paint area.
+///
+/// The thumb is sized and positioned in proportion to `viewport_len` and
+/// `offset` within `content_len`.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::VertScrollbar;
+///
+/// // Showing the second half of the content.
+/// let scrollbar = VertScrollbar::new(4, 2, 2);
+///
+/// let mut backend = MemBackend::new(1, 4);
+/// backend.render(|buf| scrollbar.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "│\n│\n█\n█");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VertScrollbar {
+    pub content_len: usize,
+    pub viewport_len: usize,
+    pub offset: usize,
+    pub theme: Theme,
+}
+
+impl VertScrollbar {
+    /// Creates a new `VertScrollbar`.
+    #[inline]
+    pub const fn new(content_len: usize, viewport_len: usize, offset: usize)
+        -> Self
+    {
+        Self {
+            content_len,
+            viewport_len,
+            offset,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Adjusts the theme of the `VertScrollbar`.
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+}
+
+impl<R: Render> Draw<R> for VertScrollbar {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let (thumb_start, thumb_len) = thumb(
+            self.content_len,
+            self.viewport_len,
+            self.offset,
+            area.height
+        );
+
+        let top_left = area.top_left();
+        buf.vfill(top_left, self.theme.track, area.height as usize);
+        buf.vfill(
+            top_left.add_y(thumb_start),
+            self.theme.thumb,
+            thumb_len as usize
+        );
+    }
+}
+
+impl Proportional for VertScrollbar {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::fixed(1),
+            height: Range::flexible(),
+        }
+    }
+}
+
+/// Draws a horizontal scrollbar starting at the top-left corner of the paint
+/// area and spanning the full width of the paint area.
+///
+/// The thumb is sized and positioned in proportion to `viewport_len` and
+/// `offset` within `content_len`. When all of the content fits in the
+/// viewport, the thumb fills the whole track.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::HorizScrollbar;
+///
+/// let mut backend = MemBackend::new(8, 1);
+///
+/// let scrollbar = HorizScrollbar::new(20, 5, 10);
+/// backend.render(|buf| scrollbar.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "────██──");
+///
+/// // The thumb never runs past the end of the track.
+/// let scrollbar = HorizScrollbar::new(20, 5, 19);
+/// backend.render(|buf| scrollbar.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "──────██");
+///
+/// let scrollbar = HorizScrollbar::new(3, 5, 0);
+/// backend.render(|buf| scrollbar.draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "████████");
+/// ```
+#[derive(Debug, Clone)]
+pub struct HorizScrollbar {
+    pub content_len: usize,
+    pub viewport_len: usize,
+    pub offset: usize,
+    pub theme: Theme,
+}
+
+impl HorizScrollbar {
+    /// Creates a new `HorizScrollbar`.
+    #[inline]
+    pub const fn new(content_len: usize, viewport_len: usize, offset: usize)
+        -> Self
+    {
+        Self {
+            content_len,
+            viewport_len,
+            offset,
+            theme: Theme::horiz(),
+        }
+    }
+
+    /// Adjusts the theme of the `HorizScrollbar`.
+    #[inline]
+    pub const fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+}
+
+impl Default for HorizScrollbar {
+    fn default() -> Self
+    {
+        Self::new(0, 0, 0)
+    }
+}
+
+impl<R: Render> Draw<R> for HorizScrollbar {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let (thumb_start, thumb_len) = thumb(
+            self.content_len,
+            self.viewport_len,
+            self.offset,
+            area.width
+        );
+
+        let top_left = area.top_left();
+        buf.hfill(top_left, self.theme.track, area.width as usize);
+        buf.hfill(
+            top_left.add_x(thumb_start),
+            self.theme.thumb,
+            thumb_len as usize
+        );
+    }
+}
+
+impl Proportional for HorizScrollbar {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::fixed(1),
+        }
+    }
+}
+
+
+/// Computes the start and the length of the thumb in a track of `track_len`.
+fn thumb(content_len: usize, viewport_len: usize, offset: usize, track_len: u16)
+    -> (u16, u16)
+{
+    if content_len <= viewport_len {
+        return (0, track_len);
+    }
+
+    let track = track_len as f64;
+    let content = content_len as f64;
+
+    let len = (viewport_len as f64 / content * track).round() as u16;
+    let len = len.clamp(1, track_len);
+
+    let start = (offset as f64 / content * track).round() as u16;
+    let start = std::cmp::min(start, track_len - len);
+
+    (start, len)
+}
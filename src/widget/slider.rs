@@ -0,0 +1,222 @@
+use std::cell::Cell;
+
+use termion::event::{Event, Key, MouseButton, MouseEvent};
+
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::style::{Style, StyledChar};
+
+use super::{mouse_pos, Draw, InteractiveWidget, Render};
+
+
+/// Configuration options for theming [`Slider`].
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub track: StyledChar,
+    pub handle: StyledChar,
+}
+
+impl Theme {
+    /// Const version of `Default::default`.
+    #[inline]
+    pub const fn default() -> Self
+    {
+        Self {
+            track: StyledChar { content: '─', style: Style::default() },
+            handle: StyledChar { content: '●', style: Style::default() },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self
+    {
+        Self::default()
+    }
+}
+
+/// Horizontal slider representing a value in `min..=max`.
+///
+/// Left and Right change the value by `step`, Home and End jump to the
+/// extremes. Clicking on the track sets the value based on the position of the
+/// click, rounded to the nearest step. The first and the last column always
+/// map to `min` and `max`.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event, Key, MouseButton, MouseEvent};
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::{InteractiveWidget, Slider};
+///
+/// let mut slider = Slider::new(0., 10.).step(3.);
+/// let mut backend = MemBackend::new(11, 1);
+/// backend.render(|buf| slider.draw(buf, buf.area()));
+///
+/// // Terminal coordinates start at 1.
+/// let click = |x| Event::Mouse(MouseEvent::Press(MouseButton::Left, x, 1));
+///
+/// slider.process_event(click(5));
+/// assert_eq!(slider.value(), 3.);
+/// slider.process_event(click(11));
+/// assert_eq!(slider.value(), 10.);
+/// slider.process_event(click(1));
+/// assert_eq!(slider.value(), 0.);
+///
+/// // Keys that would move past the extremes are not consumed.
+/// assert!(!slider.handle_event(Event::Key(Key::Left)));
+/// assert!(slider.handle_event(Event::Key(Key::End)));
+/// assert!(!slider.handle_event(Event::Key(Key::Right)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Slider {
+    pub theme: Theme,
+    pub step: f64,
+    min: f64,
+    max: f64,
+    value: f64,
+    // HACK: FIXME: this is state related purely to drawing.
+    area: Cell<Area>,
+}
+
+impl Slider {
+    /// Creates a new `Slider` set to `min`, with a step of `1`.
+    ///
+    /// If `max` is less than `min`, they are swapped.
+    pub fn new(min: f64, max: f64) -> Self
+    {
+        let (min, max) = if max < min { (max, min) } else { (min, max) };
+
+        Self {
+            theme: Theme::default(),
+            step: 1.,
+            min,
+            max,
+            value: min,
+            area: Cell::new(Area::default()),
+        }
+    }
+
+    /// Adjusts the step.
+    #[inline]
+    pub fn step(mut self, step: f64) -> Self
+    {
+        self.step = step;
+
+        self
+    }
+
+    /// Adjusts the theme.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self
+    {
+        self.theme = theme;
+
+        self
+    }
+
+    /// Gets the value.
+    #[inline]
+    pub fn value(&self) -> f64
+    {
+        self.value
+    }
+
+    /// Sets the value, clamped to `min..=max`.
+    #[inline]
+    pub fn set_value(&mut self, value: f64)
+    {
+        self.value = value.clamp(self.min, self.max);
+    }
+
+    /// Maps column `x` of a track of `width` cells to a value, rounded to the
+    /// nearest step.
+    ///
+    /// The extremes are mapped to `min` and `max` before rounding, so that
+    /// `max` can be reached even if it is not a whole number of steps away.
+    fn value_at(&self, x: u16, width: u16) -> f64
+    {
+        if x == 0 || width <= 1 {
+            return self.min;
+        }
+        if x >= width - 1 {
+            return self.max;
+        }
+
+        let ratio = x as f64 / (width - 1) as f64;
+        let value = self.min + ratio * (self.max - self.min);
+
+        if self.step > 0. {
+            let steps = ((value - self.min) / self.step).round();
+            (self.min + steps * self.step).clamp(self.min, self.max)
+        } else {
+            value.clamp(self.min, self.max)
+        }
+    }
+}
+
+impl<R: Render> Draw<R> for Slider {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        self.area.set(area);
+
+        if area.is_collapsed() {
+            return;
+        }
+
+        let ratio = if self.max > self.min
+            { (self.value - self.min) / (self.max - self.min) }
+            else { 0. };
+        let handle_x = (ratio * (area.width - 1) as f64).round() as u16;
+
+        let top_left = area.top_left();
+        buf.hfill(top_left, self.theme.track, area.width as usize);
+        buf.putc_abs(top_left.add_x(handle_x), self.theme.handle);
+    }
+}
+
+impl Proportional for Slider {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::fixed(1),
+        }
+    }
+}
+
+impl InteractiveWidget for Slider {
+    fn process_event(&mut self, e: Event)
+    {
+        self.handle_event(e);
+    }
+
+    fn handle_event(&mut self, e: Event) -> bool
+    {
+        match e {
+            Event::Key(Key::Left) if self.value > self.min => {
+                self.set_value(self.value - self.step);
+            },
+            Event::Key(Key::Right) if self.value < self.max => {
+                self.set_value(self.value + self.step);
+            },
+            Event::Key(Key::Home) if self.value > self.min => {
+                self.value = self.min;
+            },
+            Event::Key(Key::End) if self.value < self.max => {
+                self.value = self.max;
+            },
+            Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y))
+                if self.area.get().contains_pos(mouse_pos(x, y)) =>
+            {
+                let area = self.area.get();
+                let pos = mouse_pos(x, y);
+
+                self.value = self.value_at(pos.x - area.x, area.width);
+            },
+            _ => return false,
+        }
+
+        true
+    }
+}
@@ -0,0 +1,93 @@
+use crate::layout::{Area, Proportional, Proportions, Range};
+use crate::style::{Style, WithStyle};
+
+use super::{Draw, Render};
+
+
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Draws `data` as a single row of block glyphs, scaled to the maximum value.
+///
+/// When there are more data points than the width of the paint area, the most
+/// recent (last) ones are shown. Otherwise, the data are left-aligned.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::Sparkline;
+///
+/// let mut backend = MemBackend::new(6, 1);
+///
+/// backend.render(|buf| Sparkline::new(&[0, 2, 4, 8]).draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "▁▃▅█  ");
+///
+/// // Only the last 6 values fit, and they are scaled to their own maximum.
+/// let data = [9, 9, 0, 1, 2, 3, 4, 5];
+/// backend.render(|buf| Sparkline::new(&data).draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "▁▂▄▅▇█");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sparkline<'a> {
+    pub data: &'a [u64],
+    pub style: Style,
+}
+
+impl<'a> Sparkline<'a> {
+    /// Creates a new `Sparkline`.
+    #[inline]
+    pub const fn new(data: &'a [u64]) -> Self
+    {
+        Self {
+            data,
+            style: Style::default(),
+        }
+    }
+
+    /// Adjusts the style of the glyphs.
+    #[inline]
+    pub const fn style(mut self, style: Style) -> Self
+    {
+        self.style = style;
+
+        self
+    }
+}
+
+impl<R: Render> Draw<R> for Sparkline<'_> {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        if area.is_collapsed() {
+            return;
+        }
+
+        let start = self.data.len().saturating_sub(area.width as usize);
+        let data = &self.data[start..];
+        let max = data.iter().copied().max().unwrap_or(0);
+
+        for (x, &value) in data.iter().enumerate() {
+            let level = if max == 0
+                { 0 }
+                else {
+                    let top = (BLOCKS.len() - 1) as u128;
+                    (value as u128 * top + max as u128 / 2) / max as u128
+                };
+            let c = BLOCKS[level as usize].with_style(|_| self.style);
+
+            buf.putc_abs(area.top_left().add_x(x as u16), c);
+        }
+    }
+}
+
+impl Proportional for Sparkline<'_> {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions {
+            width: Range::flexible(),
+            height: Range::fixed(1),
+        }
+    }
+}
@@ -1,5 +1,8 @@
 //! Lists of widgets, rendered in equally-sized cells.
 //!
+//! [`WeightedRow`] and [`WeightedCol`] size the cells according to explicit
+//! weights instead.
+//!
 //! Note that these widgets have fully flexible proportions
 //! [`Proportional`](crate::layout::Proportional) as they ignore their items'
 //! proportions anyway. To control the size of the splits, wrap them in a
@@ -13,7 +16,7 @@
 //! # Example
 //!
 //! ```
-//! use cwinui::alloc::buffer::Buffer;
+//! use cwinui::buffer::Buffer;
 //! use cwinui::widget::{
 //!     layout::{Container, Center},
 //!     Row,
@@ -112,3 +115,183 @@ impl<R: Render> Proportional for Row<'_, R> {
         Proportions::flexible()
     }
 }
+
+
+/// Vertical split of widgets, sized according to their weights.
+///
+/// Each item gets a share of the paint area proportional to its weight. The
+/// last item takes the rest of the area, so that the whole area is used.
+///
+/// # Example
+///
+/// Weights of `[1, 2, 2]` split a height of `10` into `2`, `4` and `4`:
+///
+/// ```
+/// use cwinui::widget::{Canvas, WeightedCol};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::layout::Dim;
+/// # #[derive(Default)]
+/// # struct Probe(std::cell::RefCell<Vec<cwinui::layout::Area>>);
+/// # impl Probe {
+/// #     fn new() -> Self { Self::default() }
+/// #     fn take(&self) -> Vec<cwinui::layout::Area> { self.0.take() }
+/// # }
+/// # impl<R: Render> Draw<R> for Probe {
+/// #     fn draw(&self, _buf: &mut R, area: cwinui::layout::Area)
+/// #     {
+/// #         self.0.borrow_mut().push(area);
+/// #     }
+/// # }
+/// # impl cwinui::layout::Proportional for Probe {
+/// #     fn proportions(&self) -> cwinui::layout::Proportions
+/// #     {
+/// #         cwinui::layout::Proportions::flexible()
+/// #     }
+/// # }
+///
+/// // `Probe` is a widget recording the areas it is drawn in.
+/// let probe = Probe::new();
+/// let mut canvas = Canvas::new(Dim { width: 1, height: 10 });
+/// let mut renderer = canvas.renderer();
+/// let area = renderer.area();
+///
+/// WeightedCol(&[(1, &probe), (2, &probe), (2, &probe)])
+///     .draw(&mut renderer, area);
+///
+/// let areas = probe.take();
+/// let heights: Vec<_> = areas.iter().map(|a| a.height).collect();
+/// let ys: Vec<_> = areas.iter().map(|a| a.y).collect();
+/// assert_eq!(heights, [2, 4, 4]);
+/// assert_eq!(ys, [0, 2, 6]);
+/// ```
+pub struct WeightedCol<'a, R: Render>(pub &'a [(u16, &'a dyn Draw<R>)]);
+
+impl<R: Render> Draw<R> for WeightedCol<'_, R> {
+    fn draw(&self, buf: &mut R, area: crate::Area)
+    {
+        if area.is_collapsed() || self.0.is_empty() {
+            return;
+        }
+
+        let total = total_weight(self.0);
+        let mut remaining = area;
+
+        let last_idx = self.0.len() - 1;
+        for &(weight, w) in &self.0[..last_idx] {
+            let size = weighted_size(area.height, weight, total);
+            let (cur_area, rest) = remaining.split_horiz_at(size);
+            remaining = rest;
+
+            w.draw(buf, cur_area);
+        }
+
+        self.0[last_idx].1.draw(buf, remaining);
+    }
+}
+
+impl<R: Render> Proportional for WeightedCol<'_, R> {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions::flexible()
+    }
+}
+
+
+/// Horizontal split of widgets, sized according to their weights.
+///
+/// Each item gets a share of the paint area proportional to its weight. The
+/// last item takes the rest of the area, so that the whole area is used.
+///
+/// # Example
+///
+/// Weights of `[1, 2, 2]` split a width of `10` into `2`, `4` and `4`, while
+/// `[1, 1, 1]` splits it into `3`, `3` and `4`:
+///
+/// ```
+/// use cwinui::widget::{Canvas, WeightedRow};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::layout::Dim;
+/// # #[derive(Default)]
+/// # struct Probe(std::cell::RefCell<Vec<cwinui::layout::Area>>);
+/// # impl Probe {
+/// #     fn new() -> Self { Self::default() }
+/// #     fn take(&self) -> Vec<cwinui::layout::Area> { self.0.take() }
+/// # }
+/// # impl<R: Render> Draw<R> for Probe {
+/// #     fn draw(&self, _buf: &mut R, area: cwinui::layout::Area)
+/// #     {
+/// #         self.0.borrow_mut().push(area);
+/// #     }
+/// # }
+/// # impl cwinui::layout::Proportional for Probe {
+/// #     fn proportions(&self) -> cwinui::layout::Proportions
+/// #     {
+/// #         cwinui::layout::Proportions::flexible()
+/// #     }
+/// # }
+///
+/// // `Probe` is a widget recording the areas it is drawn in.
+/// let probe = Probe::new();
+/// let mut canvas = Canvas::new(Dim { width: 10, height: 1 });
+/// let mut renderer = canvas.renderer();
+/// let area = renderer.area();
+///
+/// WeightedRow(&[(1, &probe), (2, &probe), (2, &probe)])
+///     .draw(&mut renderer, area);
+/// let widths: Vec<_> = probe.take().iter().map(|a| a.width).collect();
+/// assert_eq!(widths, [2, 4, 4]);
+///
+/// WeightedRow(&[(1, &probe), (1, &probe), (1, &probe)])
+///     .draw(&mut renderer, area);
+/// let widths: Vec<_> = probe.take().iter().map(|a| a.width).collect();
+/// assert_eq!(widths, [3, 3, 4]);
+/// ```
+pub struct WeightedRow<'a, R: Render>(pub &'a [(u16, &'a dyn Draw<R>)]);
+
+impl<R: Render> Draw<R> for WeightedRow<'_, R> {
+    fn draw(&self, buf: &mut R, area: crate::Area)
+    {
+        if area.is_collapsed() || self.0.is_empty() {
+            return;
+        }
+
+        let total = total_weight(self.0);
+        let mut remaining = area;
+
+        let last_idx = self.0.len() - 1;
+        for &(weight, w) in &self.0[..last_idx] {
+            let size = weighted_size(area.width, weight, total);
+            let (cur_area, rest) = remaining.split_vert_at(size);
+            remaining = rest;
+
+            w.draw(buf, cur_area);
+        }
+
+        self.0[last_idx].1.draw(buf, remaining);
+    }
+}
+
+impl<R: Render> Proportional for WeightedRow<'_, R> {
+    fn proportions(&self) -> Proportions
+    {
+        Proportions::flexible()
+    }
+}
+
+
+#[inline]
+fn total_weight<T>(items: &[(u16, T)]) -> u32
+{
+    items.iter().map(|&(weight, _)| weight as u32).sum()
+}
+
+/// Computes the share of `len` for `weight` out of `total`, rounded down.
+#[inline]
+fn weighted_size(len: u16, weight: u16, total: u32) -> u16
+{
+    if total == 0 {
+        return 0;
+    }
+
+    (len as u32 * weight as u32 / total) as u16
+}
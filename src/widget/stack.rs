@@ -0,0 +1,61 @@
+use crate::layout::{Proportional, Proportions};
+
+use super::flex::FlexItem;
+use super::{Render, Draw};
+
+
+/// Allows any number of widgets to be drawn in the same area, on top of each
+/// other.
+///
+/// The items are drawn in order, back to front: the first item is drawn first
+/// and the last one ends up on top. This is a generalization of
+/// [`Backdrop`](super::Backdrop).
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::style::StyledChar;
+/// use cwinui::widget::{Filler, Stack};
+/// use cwinui::widget::text::Line;
+///
+/// let bg = Filler(StyledChar::from('.'));
+/// let label = Line("ab");
+///
+/// let mut backend = MemBackend::new(4, 2);
+/// backend.render(|buf| Stack(&[&bg, &label]).draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "ab..\n....");
+///
+/// // In reverse order, the filler covers the label.
+/// backend.render(|buf| Stack(&[&label, &bg]).draw(buf, buf.area()));
+/// backend.flush().unwrap();
+/// assert_eq!(backend.to_string(), "....\n....");
+/// ```
+#[derive(Clone)]
+pub struct Stack<'a, R: Render>(pub &'a [&'a dyn FlexItem<R>]);
+
+impl<'a, R: Render> std::fmt::Debug for Stack<'a, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+    {
+        write!(f, "Stack({} items)", self.0.len())
+    }
+}
+
+impl<R: Render> Draw<R> for Stack<'_, R> {
+    fn draw(&self, buf: &mut R, area: crate::Area)
+    {
+        for it in self.0 {
+            it.draw(buf, area);
+        }
+    }
+}
+
+impl<R: Render> Proportional for Stack<'_, R> {
+    fn proportions(&self) -> Proportions
+    {
+        self.0.iter()
+            .fold(Proportions::ZERO, |acc, it| acc.join(it.proportions()))
+    }
+}
@@ -1,14 +1,12 @@
 use crate::render::{Render, Draw};
-use crate::style::{AsStyledStr, WithStyle};
-use crate::layout::{Pos, Proportional, Proportions, Range};
-
-#[allow(unused_imports)]
-use crate::style::StyledStr;
+use crate::style::{AsStyledStr, StyledStr, WithStyle};
+use crate::layout::{Area, Pos, Proportional, Proportions, Range};
+use crate::util::{char_width, str_width};
 
 
 /// A single [`StyledStr`] displayed on one line.
 ///
-/// Does not wrap.
+/// Does not wrap. For text in right-to-left scripts, see [`RtlLine`].
 pub struct Line<T: AsStyledStr>(pub T);
 
 impl<T: AsStyledStr, R: Render> Draw<R> for Line<T> {
@@ -31,6 +29,92 @@ impl<T: AsStyledStr> Proportional for Line<T> {
 }
 
 
+/// A single [`StyledStr`] displayed right-to-left on one line.
+///
+/// The characters are placed in reverse order, starting at the right edge of
+/// the paint area, so that text in right-to-left scripts stored in logical
+/// order reads correctly. Characters that do not fit are cut off at the left
+/// edge.
+///
+/// NOTE: this is not a full implementation of the Unicode bidirectional
+/// algorithm. All of the text is reversed, including embedded left-to-right
+/// runs (e.g. numbers or latin words), and zero-width characters (e.g.
+/// combining marks) are left out. Control characters are drawn as spaces.
+///
+/// Does not wrap.
+///
+/// # Example
+///
+/// ```
+/// use cwinui::backend::{Backend, MemBackend};
+/// use cwinui::render::{Draw, Render};
+/// use cwinui::widget::text::RtlLine;
+///
+/// let mut backend = MemBackend::new(6, 1);
+/// backend.render(|buf| RtlLine("abc").draw(buf, buf.area()));
+/// backend.flush().unwrap();
+///
+/// assert_eq!(backend.to_string(), "   cba");
+/// ```
+pub struct RtlLine<T: AsStyledStr>(pub T);
+
+impl<T: AsStyledStr, R: Render> Draw<R> for RtlLine<T> {
+    fn draw(&self, buf: &mut R, area: Area)
+    {
+        print_rtl(buf, self.0.as_styled_str(), area.width, 0, area);
+    }
+}
+
+impl<T: AsStyledStr> Proportional for RtlLine<T> {
+    fn proportions(&self) -> Proportions
+    {
+        let len = str_width(self.0.as_styled_str().content);
+        let len = std::cmp::min(len, u16::MAX as usize) as u16;
+
+        Proportions {
+            width: Range::fixed(len),
+            height: Range::fixed(1),
+        }
+    }
+}
+
+/// Prints `text` right-to-left on row `y` of `area`, with the first character
+/// ending right before column `right`.
+///
+/// Characters that do not fit to the left of `right` are left out. See
+/// [`RtlLine`] for the limitations.
+pub(crate) fn print_rtl<R: Render>(
+    buf: &mut R,
+    text: StyledStr,
+    right: u16,
+    y: u16,
+    area: Area,
+)
+{
+    let mut right = right as usize;
+
+    for (idx, c) in text.content.char_indices() {
+        let width = char_width(c);
+
+        if width == 0 {
+            continue;
+        }
+        if width > right {
+            break;
+        }
+
+        right -= width;
+        let pos = Pos { x: right as u16, y };
+
+        if c.is_control() {
+            buf.putc(pos, ' '.with_style(|_| text.style), area);
+        } else {
+            buf.print(pos, text.slice(idx..idx + c.len_utf8()), area);
+        }
+    }
+}
+
+
 // TODO: wrapping methods.
 /// A wrapping [`StyledStr`].
 ///
@@ -85,8 +169,7 @@ impl<'a, T: AsStyledStr, R: Render> Draw<R> for Chain<'a, T> {
             let link = link.as_styled_str();
             buf.print(Pos { x: offset as u16, y: 0 }, link, area);
 
-            // TODO: utf-8
-            offset += link.content.len();
+            offset += str_width(link.content);
 
             if offset >= area.width as usize { break }
         }
@@ -97,8 +180,7 @@ impl<'a, T: AsStyledStr> Proportional for Chain<'a, T> {
     fn proportions(&self) -> Proportions
     {
         let len = self.0.iter()
-            // TODO: utf-8
-            .map(|link| link.as_styled_str().content.len())
+            .map(|link| str_width(link.as_styled_str().content))
             .sum();
         let len = std::cmp::min(len, u16::MAX as usize) as u16;
 
@@ -131,7 +213,7 @@ impl<'a, T: AsStyledStr, R: Render> Draw<R> for WrapChain<'a, T> {
             let link = link.as_styled_str();
             let mut remaining = link.content;
 
-            while remaining.len() > 0 {
+            while !remaining.is_empty() {
                 let available = (area.width - x) as usize;
                 // TODO: utf-8
                 let print_len = std::cmp::min(available, remaining.len());